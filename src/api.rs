@@ -1,9 +1,28 @@
 use crate::fantasy_season::error::ApiError;
+use crate::fantasy_season::notification::{
+    Notification, NotificationMessage, Webhook, WebhookFormat,
+};
 use ergast_rs::apis::race_table::{QualifyingResult, Race, RaceResult};
 use ergast_rs::apis::response::Response;
 use reqwest::Client;
+use serde_json::{Value, json};
 use std::collections::HashMap;
 use std::time::Duration;
+use time::Date;
+
+// chat services can take longer to answer than the results api
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+// Discord rejects messages longer than this
+const DISCORD_MESSAGE_LIMIT: usize = 2000;
+
+// a race on the calendar for a season
+#[derive(Debug, Clone)]
+pub struct ScheduledRace {
+    pub name: String,
+    pub circuit: String,
+    pub country: String,
+    pub date: Date,
+}
 
 #[derive(Default)]
 pub struct Api {
@@ -19,7 +38,7 @@ impl Api {
                 .unwrap(),
         }
     }
-    pub async fn get_race_names(&self, season: u16) -> Result<HashMap<u8, String>, ApiError> {
+    pub async fn get_schedule(&self, season: u16) -> Result<HashMap<u8, ScheduledRace>, ApiError> {
         let mut map = HashMap::new();
         self.client
             .get(format!("https://api.jolpi.ca/ergast/f1/{season}/races/"))
@@ -35,11 +54,36 @@ impl Api {
             .races
             .into_iter()
             .for_each(|r| {
-                map.insert(r.round as u8, r.name);
+                map.insert(
+                    r.round as u8,
+                    ScheduledRace {
+                        name: r.name,
+                        circuit: r.circuit.circuit_name,
+                        country: r.circuit.location.country,
+                        date: r.date,
+                    },
+                );
             });
         Ok(map)
     }
 
+    // posts a JSON body to a webhook, failing if it is not accepted
+    pub async fn post_webhook(&self, url: &str, body: &Value) -> Result<(), ApiError> {
+        let response = self
+            .client
+            .post(url)
+            .timeout(WEBHOOK_TIMEOUT)
+            .json(body)
+            .send()
+            .await
+            .map_err(|_| ApiError::CannotConnectToServer)?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(ApiError::WebhookRejected(response.status().as_u16()))
+        }
+    }
+
     pub async fn get_race_results(
         &self,
         season: u16,
@@ -91,3 +135,289 @@ impl Api {
             .races)
     }
 }
+
+// the url and JSON body of the request to send to each webhook for a notification
+pub fn webhook_requests(
+    webhooks: &[Webhook],
+    message: &NotificationMessage,
+) -> Vec<(String, Value)> {
+    webhooks
+        .iter()
+        .map(|w| {
+            (
+                w.get_url().to_string(),
+                webhook_body(w.get_format(), message),
+            )
+        })
+        .collect()
+}
+
+// the JSON body to send for a notification, in the format a webhook expects
+fn webhook_body(format: WebhookFormat, message: &NotificationMessage) -> Value {
+    match format {
+        WebhookFormat::Generic => json!({
+            "event": match message.notification {
+                Notification::RoundScored(_) => "round_scored",
+                Notification::ResultsAvailable(_) => "results_available",
+                Notification::Test => "test",
+            },
+            "season": message.season,
+            "year": message.year,
+            "round": message.notification.round(),
+            "message": message.text,
+            "report": message.report,
+        }),
+        WebhookFormat::Discord => {
+            json!({ "content": truncate(&message.text, DISCORD_MESSAGE_LIMIT) })
+        }
+        WebhookFormat::Slack => json!({ "text": message.text }),
+    }
+}
+
+// cuts a message down to a number of characters, closing the code block if the cut is inside one
+fn truncate(message: &str, limit: usize) -> String {
+    if message.chars().count() <= limit {
+        return message.to_string();
+    }
+    let ending = "\n...\n```";
+    let mut cut: String = message
+        .chars()
+        .take(limit - ending.chars().count())
+        .collect();
+    if cut.matches("```").count() % 2 == 1 {
+        cut.push_str(ending);
+    } else {
+        cut.push_str("\n...");
+    }
+    cut
+}
+
+// sends every webhook request, returning the first error once they have all been tried
+pub async fn send_notifications(requests: Vec<(String, Value)>) -> Result<(), ApiError> {
+    let api = Api::new();
+    let mut result = Ok(());
+    for (url, body) in requests {
+        if let Err(err) = api.post_webhook(&url, &body).await {
+            result = result.and(Err(err));
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fantasy_season::FantasySeason;
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+
+    fn season() -> FantasySeason {
+        FantasySeason::new(
+            String::from("Office League"),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            [String::from("Alpha"), String::from("Bravo")],
+            2,
+            2024,
+            20,
+            true,
+        )
+    }
+
+    // what the stub server was sent
+    struct Received {
+        request_line: String,
+        content_type: Option<String>,
+        body: Value,
+    }
+
+    // a local server that answers a single request with a status, returning its url and what it
+    // was sent
+    async fn stub(status: u16) -> (String, tokio::task::JoinHandle<Received>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let received = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).await.unwrap();
+
+            let mut content_type = None;
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).await.unwrap();
+                let Some((name, value)) = line.trim_end().split_once(':') else {
+                    break;
+                };
+                match name.to_lowercase().as_str() {
+                    "content-type" => content_type = Some(value.trim().to_string()),
+                    "content-length" => length = value.trim().parse().unwrap(),
+                    _ => {}
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).await.unwrap();
+
+            let response =
+                format!("HTTP/1.1 {status} Stub\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+            reader
+                .get_mut()
+                .write_all(response.as_bytes())
+                .await
+                .unwrap();
+            Received {
+                request_line,
+                content_type,
+                body: serde_json::from_slice(&body).unwrap(),
+            }
+        });
+        (url, received)
+    }
+
+    fn keys(body: &Value) -> Vec<&str> {
+        let mut keys: Vec<_> = body
+            .as_object()
+            .expect("bodies are JSON objects")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        keys
+    }
+
+    #[test]
+    fn short_messages_are_not_truncated() {
+        assert_eq!(
+            truncate("round 1 has been scored", 2000),
+            "round 1 has been scored"
+        );
+    }
+
+    #[test]
+    fn truncating_closes_an_open_code_block() {
+        let message = format!("standings\n```\n{}\n```", "1. Alpha 25\n".repeat(500));
+        let cut = truncate(&message, DISCORD_MESSAGE_LIMIT);
+
+        assert!(cut.chars().count() <= DISCORD_MESSAGE_LIMIT);
+        assert!(cut.ends_with("\n...\n```"));
+        assert_eq!(cut.matches("```").count() % 2, 0);
+    }
+
+    #[test]
+    fn truncating_outside_a_code_block_adds_no_fence() {
+        let message = "a".repeat(3000);
+        let cut = truncate(&message, DISCORD_MESSAGE_LIMIT);
+
+        assert!(cut.chars().count() <= DISCORD_MESSAGE_LIMIT);
+        assert!(cut.ends_with("\n..."));
+        assert!(!cut.contains("```"));
+    }
+
+    #[test]
+    fn generic_body_has_the_event_and_report() {
+        let message = season().get_notification_message(Notification::RoundScored(3));
+        let body = webhook_body(WebhookFormat::Generic, &message);
+
+        assert_eq!(
+            keys(&body),
+            ["event", "message", "report", "round", "season", "year"]
+        );
+        assert_eq!(body["event"], "round_scored");
+        assert_eq!(body["season"], "Office League");
+        assert_eq!(body["year"], 2024);
+        assert_eq!(body["round"], 3);
+        assert_eq!(body["message"], message.text);
+        assert_eq!(body["report"]["round"], 3);
+        assert_eq!(body["report"]["teams"].as_array().map(Vec::len), Some(2));
+    }
+
+    #[test]
+    fn generic_body_for_a_test_has_no_round() {
+        let message = season().get_notification_message(Notification::Test);
+        let body = webhook_body(WebhookFormat::Generic, &message);
+
+        assert_eq!(body["event"], "test");
+        assert!(body["round"].is_null());
+        assert!(body["report"].is_null());
+    }
+
+    #[test]
+    fn discord_body_is_content() {
+        let message = season().get_notification_message(Notification::RoundScored(1));
+        let body = webhook_body(WebhookFormat::Discord, &message);
+
+        assert_eq!(keys(&body), ["content"]);
+        assert_eq!(body["content"], message.text);
+    }
+
+    #[test]
+    fn slack_body_is_text() {
+        let message = season().get_notification_message(Notification::ResultsAvailable(1));
+        let body = webhook_body(WebhookFormat::Slack, &message);
+
+        assert_eq!(keys(&body), ["text"]);
+        assert_eq!(body["text"], message.text);
+    }
+
+    #[test]
+    fn every_webhook_gets_a_request() {
+        let webhooks = [
+            Webhook::new(String::from("https://a.example"), WebhookFormat::Slack).unwrap(),
+            Webhook::new(String::from("https://b.example"), WebhookFormat::Discord).unwrap(),
+        ];
+        let message = season().get_notification_message(Notification::Test);
+        let requests = webhook_requests(&webhooks, &message);
+
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].0, "https://a.example");
+        assert_eq!(keys(&requests[0].1), ["text"]);
+        assert_eq!(requests[1].0, "https://b.example");
+        assert_eq!(keys(&requests[1].1), ["content"]);
+    }
+
+    #[tokio::test]
+    async fn webhooks_are_posted_as_json() {
+        let (url, received) = stub(204).await;
+        let body = json!({ "text": "Office League 2024: round 1 has been scored" });
+
+        assert!(Api::new().post_webhook(&url, &body).await.is_ok());
+        let received = received.await.unwrap();
+        assert!(received.request_line.starts_with("POST /hook "));
+        assert_eq!(received.content_type.as_deref(), Some("application/json"));
+        assert_eq!(received.body, body);
+    }
+
+    #[tokio::test]
+    async fn a_webhook_answering_with_an_error_is_rejected() {
+        let (url, received) = stub(500).await;
+
+        let result = Api::new().post_webhook(&url, &json!({ "text": "" })).await;
+        assert!(matches!(result, Err(ApiError::WebhookRejected(500))));
+        received.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn every_webhook_is_sent_to_when_one_rejects() {
+        let (rejecting, rejected) = stub(404).await;
+        let (accepting, accepted) = stub(200).await;
+        let webhooks = [
+            Webhook::new(rejecting, WebhookFormat::Slack).unwrap(),
+            Webhook::new(accepting, WebhookFormat::Discord).unwrap(),
+        ];
+        let message = season().get_notification_message(Notification::Test);
+
+        let result = send_notifications(webhook_requests(&webhooks, &message)).await;
+        assert!(matches!(result, Err(ApiError::WebhookRejected(404))));
+        assert_eq!(
+            rejected.await.unwrap().body,
+            json!({ "text": message.text })
+        );
+        assert_eq!(
+            accepted.await.unwrap().body,
+            json!({ "content": message.text })
+        );
+    }
+}
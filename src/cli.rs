@@ -0,0 +1,309 @@
+mod serve;
+
+use crate::api::{self, Api};
+use crate::export::{self, ExportFormat};
+use crate::fantasy_season::FantasySeason;
+use crate::fantasy_season::draft::{self, DraftChoice, Drafter, ReplaceAll, RollOn, Skip};
+use crate::fantasy_season::notification::Notification;
+use crate::fantasy_season::race_results::RaceResults;
+use crate::save;
+use std::path::Path;
+
+const USAGE: &str = "usage: f1fm [command]
+
+with no command, the F1FM app is opened
+
+commands:
+  list                                list every saved season
+  standings <season> <round>          show the points for and up to a round
+  download <season> <round>           download the results for a round
+  draft <season> <round> [lineups]    draft a round, reading lineups from a file if needed
+  score <season> <round>              score a round
+  catch-up <season>                   download, draft, and score every round that can be
+  export <season> <path>              write a season to a JSON file
+  report <season> <round> <path>      write a round report to a .csv, .md, .html, or .png file
+  site <season> <directory>           write a season to a directory of HTML pages
+  serve [address]                     serve the seasons as JSON over HTTP, on 127.0.0.1:8080 by default
+  help                                show this message
+
+<season> is either the id shown by list, or the name of the season.
+lineup files have one team per line in the form `team,driver1,driver2,...`
+the app should be closed while using these commands, as it will overwrite their changes";
+
+// runs a command line command, returning the exit code for the process
+pub fn run(args: Vec<String>) -> i32 {
+    let save_path = save::save_path();
+    let mut seasons = match save::load(&save_path) {
+        Ok(seasons) => seasons,
+        Err(err) => {
+            eprintln!("error: the saved seasons could not be loaded: {err}");
+            return 1;
+        }
+    };
+
+    match execute(&args, &mut seasons) {
+        Ok(changed) => {
+            if changed {
+                save::store(&save_path, &seasons.iter().collect::<Vec<_>>());
+            }
+            0
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            1
+        }
+    }
+}
+
+// executes a command, returning if the seasons were changed and need to be saved
+fn execute(args: &[String], seasons: &mut [FantasySeason]) -> Result<bool, String> {
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    match args.as_slice() {
+        ["list"] => {
+            for season in seasons.iter() {
+                println!(
+                    "{}: {} ({}, {} teams, {})",
+                    season.get_id(),
+                    season.get_name(),
+                    season.get_season(),
+                    season.get_team_count(),
+                    season.get_score_choice()
+                );
+            }
+            Ok(false)
+        }
+        ["standings", season, round] => {
+            let season = find_season(seasons, season)?;
+            let round = parse_round(round)?;
+
+            println!("points this round");
+            match season.get_points_at(round) {
+                Some(points) => print_points(&points),
+                None => println!("  round {round} has not been scored"),
+            }
+            println!("total points");
+            print_points(&season.get_points_by(round));
+            Ok(false)
+        }
+        ["download", season, round] => {
+            let season = find_season(seasons, season)?;
+            let round = parse_round(round)?;
+
+            let results = tokio::runtime::Runtime::new()
+                .map_err(|e| e.to_string())?
+                .block_on(RaceResults::build(round, season.get_season()))
+                .map_err(|e| e.to_string())?;
+            season
+                .update_results(round, results)
+                .map_err(|e| e.to_string())?;
+            println!("downloaded results for round {round}");
+            notify(season, &[Notification::ResultsAvailable(round)]);
+            Ok(true)
+        }
+        ["draft", season, round, lineups @ ..] if lineups.len() <= 1 => {
+            let season = find_season(seasons, season)?;
+            let round = parse_round(round)?;
+
+            let mut drafter: Box<dyn Drafter> = match lineups.first() {
+                None => {
+                    if season.needs_drafter(round) && round != 1 {
+                        return Err(format!(
+                            "{} seasons need a lineup file to draft",
+                            season.get_draft_choice()
+                        ));
+                    }
+                    Box::new(Skip::new())
+                }
+                Some(path) => {
+                    let text = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+                    let lineups = draft::parse_lineups(&text).map_err(|e| e.to_string())?;
+                    let roll_on = season.get_draft_choice() == DraftChoice::RollOn && round != 1;
+                    // roll on lineups only list the drivers joining each team
+                    let lineup_size = season.get_lineup_size_at(round) as usize;
+                    let lineup_size = if roll_on {
+                        let previous_size = season.get_lineup_size_at(round - 1) as usize;
+                        RollOn::picks_needed(previous_size, lineup_size)
+                    } else {
+                        lineup_size
+                    };
+                    draft::check_lineups(
+                        &lineups,
+                        Some(&season.get_drafting_team_names(round)),
+                        lineup_size,
+                        season.enforces_unique(),
+                    )
+                    .map_err(|e| e.to_string())?;
+
+                    if roll_on {
+                        let released = season.get_roll_on_releases(round).ok_or(format!(
+                            "{} seasons choose who leaves in the app",
+                            season.get_roll_on_choice()
+                        ))?;
+                        Box::new(RollOn::new(lineups.into_iter().collect(), released))
+                    } else {
+                        Box::new(ReplaceAll::new(lineups.into_iter().collect()))
+                    }
+                }
+            };
+
+            season
+                .draft(round, &mut *drafter)
+                .map_err(|e| e.to_string())?;
+            println!("drafted round {round}");
+            Ok(true)
+        }
+        ["score", season, round] => {
+            let season = find_season(seasons, season)?;
+            let round = parse_round(round)?;
+
+            season.score(round).map_err(|e| e.to_string())?;
+            println!("scored round {round}");
+            print_points(&season.get_points_at(round).unwrap_or_default());
+            notify(season, &[Notification::RoundScored(round)]);
+            Ok(true)
+        }
+        ["catch-up", season] => {
+            let season = find_season(seasons, season)?;
+
+            let results = tokio::runtime::Runtime::new()
+                .map_err(|e| e.to_string())?
+                .block_on(RaceResults::build_until_unavailable(
+                    season.get_rounds_without_results(),
+                    season.get_season(),
+                ));
+            let mut notifications = Vec::new();
+            for (round, result) in results {
+                if let Ok(rr) = result {
+                    season
+                        .update_results(round, rr)
+                        .map_err(|e| e.to_string())?;
+                    println!("downloaded results for round {round}");
+                    notifications.push(Notification::ResultsAvailable(round));
+                }
+            }
+
+            let report = season.catch_up();
+            report.summary().iter().for_each(|line| println!("{line}"));
+            notifications.extend(report.scored.iter().map(|r| Notification::RoundScored(*r)));
+            notify(season, &notifications);
+            Ok(true)
+        }
+        ["export", season, path] => {
+            let season = find_season(seasons, season)?;
+
+            let json = serde_json::to_string_pretty(season).map_err(|e| e.to_string())?;
+            std::fs::write(Path::new(path), json).map_err(|e| e.to_string())?;
+            println!("exported {} to {path}", season.get_name());
+            Ok(false)
+        }
+        ["report", season, round, path] => {
+            let season = find_season(seasons, season)?;
+            let round = parse_round(round)?;
+            let path = Path::new(path);
+            let format = ExportFormat::from_path(path).ok_or(format!(
+                "{} is not a .csv, .md, .html, or .png file",
+                path.display()
+            ))?;
+
+            export::write(path, &season.get_round_report(round), format)
+                .map_err(|e| e.to_string())?;
+            println!("exported round {round} to {}", path.display());
+            Ok(false)
+        }
+        ["site", season, dir] => {
+            let season = find_season(seasons, season)?;
+
+            let schedule = tokio::runtime::Runtime::new()
+                .map_err(|e| e.to_string())?
+                .block_on(Api::new().get_schedule(season.get_season()));
+            if let Err(err) = &schedule {
+                println!(
+                    "the calendar only lists started rounds, as the schedule could not be downloaded: {err}"
+                );
+            }
+
+            let pages = export::site::write_site(Path::new(dir), season, schedule.as_ref().ok())
+                .map_err(|e| e.to_string())?;
+            println!("wrote {pages} pages for {} to {dir}", season.get_name());
+            Ok(false)
+        }
+        ["serve", address @ ..] if address.len() <= 1 => {
+            let address = address.first().copied().unwrap_or("127.0.0.1:8080");
+            tokio::runtime::Runtime::new()
+                .map_err(|e| e.to_string())?
+                .block_on(serve::run(address, save::save_path()))
+                .map_err(|e| e.to_string())?;
+            Ok(false)
+        }
+        ["help"] | ["--help"] | ["-h"] => {
+            println!("{USAGE}");
+            Ok(false)
+        }
+        _ => Err(format!("unrecognized command\n\n{USAGE}")),
+    }
+}
+
+fn find_season<'a>(
+    seasons: &'a mut [FantasySeason],
+    id: &str,
+) -> Result<&'a mut FantasySeason, String> {
+    let idx = find_season_idx(seasons, id)?;
+    Ok(&mut seasons[idx])
+}
+
+// the index of a season, given either its id or its name. Ids are used rather than positions, as
+// the app reorders the seasons when it saves
+fn find_season_idx(seasons: &[FantasySeason], id: &str) -> Result<usize, String> {
+    id.parse::<u64>()
+        .ok()
+        .and_then(|id| seasons.iter().position(|s| s.get_id() == id))
+        .or_else(|| seasons.iter().position(|s| s.get_name() == id))
+        .ok_or(format!("no season with the id or name {id}"))
+}
+
+// sends notifications to the season's webhooks. A failed notification does not fail the command, as
+// the change it is about has already been made
+fn notify(season: &FantasySeason, notifications: &[Notification]) {
+    if season.get_webhooks().is_empty() {
+        return;
+    }
+    let requests: Vec<_> = notifications
+        .iter()
+        .flat_map(|n| {
+            api::webhook_requests(season.get_webhooks(), &season.get_notification_message(*n))
+        })
+        .collect();
+    if requests.is_empty() {
+        return;
+    }
+
+    let result = tokio::runtime::Runtime::new()
+        .map_err(|e| e.to_string())
+        .and_then(|rt| {
+            rt.block_on(api::send_notifications(requests))
+                .map_err(|e| e.to_string())
+        });
+    if let Err(err) = result {
+        eprintln!("warning: notifications could not be sent: {err}");
+    }
+}
+
+fn parse_round(round: &str) -> Result<u8, String> {
+    round
+        .parse::<u8>()
+        .ok()
+        .filter(|r| *r > 0)
+        .ok_or(format!("{round} is not a valid round"))
+}
+
+fn print_points(points: &[(String, i16)]) {
+    let width = points
+        .iter()
+        .map(|(t, _)| t.len())
+        .max()
+        .unwrap_or_default();
+    for (team, points) in points {
+        println!("  {team:width$}  {points:>5}");
+    }
+}
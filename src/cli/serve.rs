@@ -0,0 +1,226 @@
+use super::{find_season_idx, parse_round};
+use crate::fantasy_season::FantasySeason;
+use crate::save;
+use serde_json::{Value, json};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+
+// requests only have a request line and headers, so anything longer is not read
+const MAX_REQUEST_SIZE: u64 = 16 * 1024;
+// how long a client has to send its request before the connection is dropped
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+// serves the saved seasons as JSON until the process is stopped. Seasons are loaded again for
+// every request, so changes made in the app or by other commands are served straight away
+pub async fn run(address: &str, save_path: PathBuf) -> std::io::Result<()> {
+    let listener = TcpListener::bind(address).await?;
+    println!("serving seasons on http://{}", listener.local_addr()?);
+
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let save_path = save_path.clone();
+        tokio::spawn(async move {
+            if let Err(err) = handle(stream, &save_path).await {
+                eprintln!("error: {err}");
+            }
+        });
+    }
+}
+
+async fn handle(mut stream: TcpStream, save_path: &Path) -> std::io::Result<()> {
+    let request_line = tokio::time::timeout(READ_TIMEOUT, read_request(&mut stream))
+        .await
+        .map_err(|_| {
+            std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                "the client took too long to send its request",
+            )
+        })??;
+
+    let (status, body) = match request_line.split_whitespace().collect::<Vec<_>>()[..] {
+        ["GET", target, _] => match save::load(save_path) {
+            Ok(seasons) => respond(target, &seasons),
+            Err(err) => (
+                500,
+                error(&format!("the seasons could not be loaded: {err}")),
+            ),
+        },
+        [_, _, _] => (405, error("only GET requests are supported")),
+        _ => (400, error("malformed request")),
+    };
+
+    let body = body.to_string();
+    let response = format!(
+        "HTTP/1.1 {status} {}\r\n\
+         Content-Type: application/json\r\n\
+         Content-Length: {}\r\n\
+         Access-Control-Allow-Origin: *\r\n\
+         Connection: close\r\n\r\n{body}",
+        reason(status),
+        body.len(),
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+// reads the request line. The headers are not needed, but are read so the client sees its whole
+// request was received
+async fn read_request(stream: &mut TcpStream) -> std::io::Result<String> {
+    let mut reader = BufReader::new(stream.take(MAX_REQUEST_SIZE));
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).await?;
+    let mut header = String::new();
+    while reader.read_line(&mut header).await? > 0 && !header.trim().is_empty() {
+        header.clear();
+    }
+    Ok(request_line)
+}
+
+// the status code and body for a request. Routes that take a round use the latest scored round
+// when it is left out
+fn respond(target: &str, seasons: &[FantasySeason]) -> (u16, Value) {
+    let path = target.split(['?', '#']).next().unwrap_or_default();
+    let segments: Vec<String> = path
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(percent_decode)
+        .collect();
+    let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+
+    let result = match segments.as_slice() {
+        [] => Ok(json!({
+            "endpoints": [
+                "/seasons",
+                "/seasons/<season>",
+                "/seasons/<season>/standings/<round>",
+                "/seasons/<season>/lineups/<round>",
+                "/seasons/<season>/drivers/<round>",
+                "/seasons/<season>/rounds/<round>",
+            ]
+        })),
+        ["seasons"] => Ok(Value::Array(seasons.iter().map(summary).collect())),
+        ["seasons", id] => find_season_idx(seasons, id).map(|idx| summary(&seasons[idx])),
+        ["seasons", id, view, round @ ..] if round.len() <= 1 => find_season_idx(seasons, id)
+            .and_then(|idx| {
+                let season = &seasons[idx];
+                let round = match round.first() {
+                    Some(round) => parse_round(round)?,
+                    None => latest_round(season),
+                };
+                round_view(season, view, round)
+            }),
+        _ => Err(String::from("not found")),
+    };
+
+    match result {
+        Ok(body) => (200, body),
+        Err(err) => (404, error(&err)),
+    }
+}
+
+fn round_view(season: &FantasySeason, view: &str, round: u8) -> Result<Value, String> {
+    let report = season.get_round_report(round);
+    let header = |key: &str, value: Value| {
+        json!({
+            "season": report.season,
+            "year": report.year,
+            "round": report.round,
+            key: value,
+        })
+    };
+
+    match view {
+        "standings" => Ok(header(
+            "standings",
+            report
+                .teams
+                .iter()
+                .enumerate()
+                .map(|(pos, t)| {
+                    json!({
+                        "position": pos + 1,
+                        "team": t.name,
+                        "round_points": t.round_points,
+                        "total_points": t.total_points,
+                    })
+                })
+                .collect(),
+        )),
+        "lineups" => Ok(header(
+            "lineups",
+            report
+                .teams
+                .iter()
+                .map(|t| json!({ "team": t.name, "lineup": t.lineup }))
+                .collect(),
+        )),
+        "drivers" => Ok(header(
+            "drivers",
+            serde_json::to_value(&report.drivers).map_err(|e| e.to_string())?,
+        )),
+        "rounds" => serde_json::to_value(&report).map_err(|e| e.to_string()),
+        _ => Err(format!("{view} is not a round view")),
+    }
+}
+
+// the id is a string, as it is too large to be read exactly as a JSON number by JavaScript
+fn summary(season: &FantasySeason) -> Value {
+    json!({
+        "id": season.get_id().to_string(),
+        "name": season.get_name(),
+        "year": season.get_season(),
+        "score_mode": season.get_score_choice().to_string(),
+        "draft_mode": season.get_draft_choice().to_string(),
+        "teams": season.get_team_names(),
+        "latest_scored_round": season.get_first_unscored_round().saturating_sub(1),
+        "round_count": season.get_round_count(),
+        "complete": season.is_complete(),
+        "champion": season.get_champion(),
+    })
+}
+
+// the latest round that has been scored, or the first round if none have been
+fn latest_round(season: &FantasySeason) -> u8 {
+    season.get_first_unscored_round().saturating_sub(1).max(1)
+}
+
+fn error(message: &str) -> Value {
+    json!({ "error": message })
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        500 => "Internal Server Error",
+        _ => "",
+    }
+}
+
+// decodes %XX escapes, so season names with spaces can be used in paths
+fn percent_decode(segment: &str) -> String {
+    let bytes = segment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut idx = 0;
+    while idx < bytes.len() {
+        let escaped = (bytes[idx] == b'%')
+            .then(|| segment.get(idx + 1..idx + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                idx += 3;
+            }
+            None => {
+                decoded.push(bytes[idx]);
+                idx += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
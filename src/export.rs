@@ -0,0 +1,212 @@
+mod card;
+pub mod site;
+
+use crate::fantasy_season::report::RoundReport;
+use std::fmt::{Display, Write};
+use std::path::Path;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Markdown,
+    Html,
+    Png,
+}
+
+impl ExportFormat {
+    // the format a path should be written in, going by its extension
+    pub fn from_path(path: &Path) -> Option<ExportFormat> {
+        match path.extension()?.to_str()?.to_lowercase().as_str() {
+            "csv" => Some(ExportFormat::Csv),
+            "md" | "markdown" => Some(ExportFormat::Markdown),
+            "html" | "htm" => Some(ExportFormat::Html),
+            "png" => Some(ExportFormat::Png),
+            _ => None,
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Markdown => "md",
+            ExportFormat::Html => "html",
+            ExportFormat::Png => "png",
+        }
+    }
+}
+
+impl Display for ExportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            ExportFormat::Csv => String::from("CSV"),
+            ExportFormat::Markdown => String::from("Markdown"),
+            ExportFormat::Html => String::from("HTML"),
+            ExportFormat::Png => String::from("PNG image"),
+        };
+        write!(f, "{str}")
+    }
+}
+
+pub fn write(path: &Path, report: &RoundReport, format: ExportFormat) -> std::io::Result<()> {
+    std::fs::write(path, render(report, format)?)
+}
+
+// the contents of the exported file. PNG images are drawn by the card module, the other formats
+// are text
+pub fn render(report: &RoundReport, format: ExportFormat) -> std::io::Result<Vec<u8>> {
+    let title = format!("{} {} round {}", report.season, report.year, report.round);
+    let (team_rows, driver_rows) = rows(report);
+
+    let text = match format {
+        ExportFormat::Csv => {
+            let mut csv = String::new();
+            for row in std::iter::once(TEAM_HEADER.map(String::from).to_vec()).chain(team_rows) {
+                writeln!(csv, "{}", csv_row(&row)).unwrap();
+            }
+            writeln!(csv).unwrap();
+            for row in std::iter::once(DRIVER_HEADER.map(String::from).to_vec()).chain(driver_rows)
+            {
+                writeln!(csv, "{}", csv_row(&row)).unwrap();
+            }
+            csv
+        }
+        ExportFormat::Markdown => {
+            let mut md = format!("# {}\n\n## standings\n\n", markdown_cell(&title));
+            md.push_str(&markdown_table(&TEAM_HEADER, &team_rows));
+            md.push_str("\n## driver performance\n\n");
+            md.push_str(&markdown_table(&DRIVER_HEADER, &driver_rows));
+            md
+        }
+        ExportFormat::Html => {
+            let title = html_escape(&title);
+            format!(
+                "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n\
+                 <style>{HTML_STYLE}</style>\n</head>\n<body>\n<h1>{title}</h1>\n\
+                 <h2>standings</h2>\n{}<h2>driver performance</h2>\n{}</body>\n</html>\n",
+                html_table(&TEAM_HEADER, &team_rows),
+                html_table(&DRIVER_HEADER, &driver_rows),
+            )
+        }
+        ExportFormat::Png => return card::render(report),
+    };
+    Ok(text.into_bytes())
+}
+
+const TEAM_HEADER: [&str; 5] = [
+    "position",
+    "team",
+    "points this round",
+    "total points",
+    "lineup",
+];
+const DRIVER_HEADER: [&str; 3] = ["driver", "points this round", "total points"];
+const HTML_STYLE: &str = "body{font-family:sans-serif;background:#423e3b;color:#fcf7f8}\
+    table{border-collapse:collapse;margin-bottom:2em}\
+    th,td{padding:4px 12px;border-bottom:1px solid #6b6561;text-align:left}\
+    th{color:#ff6319}";
+
+// the cells of the team and driver tables. Rounds that have not been scored or drafted are left
+// empty
+fn rows(report: &RoundReport) -> (Vec<Vec<String>>, Vec<Vec<String>>) {
+    let teams = report
+        .teams
+        .iter()
+        .enumerate()
+        .map(|(pos, team)| {
+            vec![
+                (pos + 1).to_string(),
+                team.name.clone(),
+                team.round_points.map(|p| p.to_string()).unwrap_or_default(),
+                team.total_points.to_string(),
+                team.lineup
+                    .as_ref()
+                    .map(|l| {
+                        l.iter()
+                            .map(|d| format!("{d:02}"))
+                            .collect::<Vec<_>>()
+                            .join(" ")
+                    })
+                    .unwrap_or_default(),
+            ]
+        })
+        .collect();
+
+    let drivers = report
+        .drivers
+        .iter()
+        .map(|driver| {
+            vec![
+                format!("{:02}", driver.driver),
+                driver
+                    .round_points
+                    .map(|p| p.to_string())
+                    .unwrap_or_default(),
+                driver.total_points.to_string(),
+            ]
+        })
+        .collect();
+
+    (teams, drivers)
+}
+
+// quotes every cell that contains a comma, quote, or new line
+fn csv_row(cells: &[String]) -> String {
+    cells
+        .iter()
+        .map(|cell| {
+            if cell.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            } else {
+                cell.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn markdown_cell(cell: &str) -> String {
+    cell.replace('\\', "\\\\").replace('|', "\\|")
+}
+
+fn markdown_table(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut table = format!("| {} |\n", header.join(" | "));
+    table.push_str(&format!("|{}\n", "---|".repeat(header.len())));
+    for row in rows {
+        let cells: Vec<_> = row.iter().map(|c| markdown_cell(c)).collect();
+        table.push_str(&format!("| {} |\n", cells.join(" | ")));
+    }
+    table
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn html_table(header: &[&str], rows: &[Vec<String>]) -> String {
+    let rows: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| row.iter().map(|cell| html_escape(cell)).collect())
+        .collect();
+    html_table_raw(header, &rows)
+}
+
+// a table whose cells are already HTML, so they can contain links
+fn html_table_raw(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut table = String::from("<table>\n<tr>");
+    for cell in header {
+        write!(table, "<th>{}</th>", html_escape(cell)).unwrap();
+    }
+    table.push_str("</tr>\n");
+    for row in rows {
+        table.push_str("<tr>");
+        for cell in row {
+            write!(table, "<td>{cell}</td>").unwrap();
+        }
+        table.push_str("</tr>\n");
+    }
+    table.push_str("</table>\n");
+    table
+}
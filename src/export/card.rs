@@ -0,0 +1,507 @@
+use crate::fantasy_season::report::RoundReport;
+use cosmic_text::{Attrs, Buffer, Family, FontSystem, Metrics, Shaping, SwashCache, Weight};
+use iced::Color;
+use iced::theme::palette::Extended;
+use tiny_skia::{FillRule, Paint, Path, PathBuilder, Pixmap, Rect, Stroke, Transform};
+
+// the same sizes as the season view, so the card looks like a screenshot of it
+const PADDING: f32 = 7.0;
+const MARGIN: f32 = 20.0;
+const TITLE: f32 = 24.0;
+const CONTENT: f32 = 20.0;
+const LINE_HEIGHT: f32 = 1.3;
+const TABLE_PADDING: f32 = 3.0;
+const BORDER: f32 = 2.0;
+const RADIUS: f32 = 5.0;
+
+#[derive(Copy, Clone)]
+enum Font {
+    F1,
+    Mono,
+}
+
+// the round drawn as the season view shows it: the points, total points, and driver performance
+// this round, then the lineups and driver performance up to the round. Drawn on the CPU with the
+// bundled fonts, so it works without a GPU or any installed fonts
+pub(super) fn render(report: &RoundReport) -> std::io::Result<Vec<u8>> {
+    let palette = Extended::generate(crate::PALETTE);
+    let mut card = Card::new();
+
+    let title = card.text(
+        &format!("{} {} round {}", report.season, report.year, report.round),
+        Font::F1,
+        TITLE,
+    );
+    let rows: Vec<Vec<Table>> = tables(report)
+        .into_iter()
+        .map(|row| row.into_iter().map(|t| t.shape(&mut card)).collect())
+        .collect();
+
+    let row_size = |row: &Vec<Table>| {
+        (
+            row.iter().map(|t| t.width).sum::<f32>() + PADDING * row.len().saturating_sub(1) as f32,
+            row.iter().map(|t| t.height).fold(0.0, f32::max),
+        )
+    };
+    let content_width = rows
+        .iter()
+        .map(|row| row_size(row).0)
+        .fold(title.width, f32::max);
+    let content_height = title.height
+        + rows
+            .iter()
+            .map(|row| PADDING + row_size(row).1)
+            .sum::<f32>();
+
+    let mut pixmap = Pixmap::new(
+        (content_width + MARGIN * 2.0).ceil() as u32,
+        (content_height + MARGIN * 2.0).ceil() as u32,
+    )
+    .ok_or(std::io::Error::other("the card has no size"))?;
+    pixmap.fill(skia_color(palette.background.base.color));
+
+    let center = MARGIN + content_width / 2.0;
+    let mut y = MARGIN;
+    card.draw_text(
+        &mut pixmap,
+        &title,
+        center - title.width / 2.0,
+        y,
+        palette.background.base.text,
+    );
+    y += title.height;
+
+    for row in &rows {
+        y += PADDING;
+        let (width, height) = row_size(row);
+        let mut x = center - width / 2.0;
+        for table in row {
+            table.draw(&mut card, &mut pixmap, &palette, x, y);
+            x += table.width + PADDING;
+        }
+        y += height;
+    }
+
+    pixmap.encode_png().map_err(std::io::Error::other)
+}
+
+// the points of the teams scored this round, so teams that had retired or not yet joined are left
+// out. Ties go to the team with more points overall, and then by name
+fn round_points(report: &RoundReport) -> Vec<(String, String)> {
+    let mut teams: Vec<_> = report
+        .teams
+        .iter()
+        .filter_map(|t| Some((t.round_points?, t.total_points, &t.name)))
+        .collect();
+    teams.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.cmp(&a.1)).then(a.2.cmp(b.2)));
+    if teams.is_empty() {
+        return vec![(String::from("not yet scored"), String::new())];
+    }
+
+    teams
+        .into_iter()
+        .map(|(points, _, team)| (team.clone(), points.to_string()))
+        .collect()
+}
+
+// the tables of the season view, in the rows it shows them in
+fn tables(report: &RoundReport) -> Vec<Vec<TableSpec>> {
+    let team_table = |title: &str, rows: Vec<(String, String)>| {
+        let (teams, data): (Vec<_>, Vec<_>) = rows.into_iter().unzip();
+        TableSpec {
+            title: title.to_string(),
+            columns: vec![
+                ColumnSpec {
+                    cells: teams,
+                    align_end: false,
+                    spacing: 0.0,
+                },
+                ColumnSpec {
+                    cells: data,
+                    align_end: true,
+                    spacing: PADDING * 2.0,
+                },
+            ],
+        }
+    };
+
+    let points_at = team_table("points this round", round_points(report));
+
+    let points_by = team_table(
+        "total points",
+        report
+            .teams
+            .iter()
+            .map(|t| (t.name.clone(), t.total_points.to_string()))
+            .collect(),
+    );
+
+    let mut lineups: Vec<_> = report
+        .teams
+        .iter()
+        .map(|t| {
+            let lineup = match &t.lineup {
+                Some(lineup) => lineup.iter().map(|d| format!(" {d:0>2}")).collect(),
+                None => String::from("not yet drafted"),
+            };
+            (t.name.clone(), lineup)
+        })
+        .collect();
+    lineups.sort();
+    let lineups = team_table("lineup this round", lineups);
+
+    let rows = report.teams.len();
+    let mut perf_at: Vec<_> = report
+        .drivers
+        .iter()
+        .filter_map(|d| Some((d.driver, d.round_points?)))
+        .collect();
+    let perf_at = if perf_at.is_empty() {
+        TableSpec {
+            title: String::from("performance this round"),
+            columns: vec![ColumnSpec {
+                cells: vec![String::from("no driver performance data yet"); rows],
+                align_end: false,
+                spacing: 0.0,
+            }],
+        }
+    } else {
+        perf_at.sort_by(|a, b| (b.1).cmp(&a.1).then(b.0.cmp(&a.0)));
+        driver_table("performance this round", rows, perf_at)
+    };
+    let perf_by = driver_table(
+        "performance",
+        rows,
+        report
+            .drivers
+            .iter()
+            .map(|d| (d.driver, d.total_points))
+            .collect(),
+    );
+
+    vec![vec![points_at, points_by, perf_at], vec![lineups, perf_by]]
+}
+
+// drivers and their points, split into columns as tall as the other tables
+fn driver_table(title: &str, rows: usize, driver_perf: Vec<(u8, i16)>) -> TableSpec {
+    let columns = driver_perf
+        .chunks(rows.max(1))
+        .enumerate()
+        .flat_map(|(idx, chunk)| {
+            [
+                ColumnSpec {
+                    cells: chunk.iter().map(|d| format!("{:02}:", d.0)).collect(),
+                    align_end: false,
+                    spacing: if idx == 0 { 0.0 } else { PADDING * 2.0 },
+                },
+                ColumnSpec {
+                    cells: chunk.iter().map(|d| d.1.to_string()).collect(),
+                    align_end: false,
+                    spacing: 0.0,
+                },
+            ]
+        })
+        .collect();
+
+    TableSpec {
+        title: title.to_string(),
+        columns,
+    }
+}
+
+struct TableSpec {
+    title: String,
+    columns: Vec<ColumnSpec>,
+}
+
+struct ColumnSpec {
+    cells: Vec<String>,
+    align_end: bool,
+    spacing: f32,
+}
+
+impl TableSpec {
+    fn shape(self, card: &mut Card) -> Table {
+        let title = card.text(&self.title, Font::Mono, CONTENT);
+        let columns: Vec<_> = self
+            .columns
+            .into_iter()
+            .map(|c| {
+                let cells: Vec<_> = c
+                    .cells
+                    .iter()
+                    .map(|cell| card.text(cell, Font::Mono, CONTENT))
+                    .collect();
+                Column {
+                    width: cells.iter().map(|c| c.width).fold(0.0, f32::max),
+                    cells,
+                    align_end: c.align_end,
+                    spacing: c.spacing,
+                }
+            })
+            .collect();
+
+        let inner_width =
+            columns.iter().map(|c| c.spacing + c.width).sum::<f32>() + TABLE_PADDING * 2.0;
+        let inner_height = columns
+            .iter()
+            .map(|c| c.cells.iter().map(|t| t.height).sum::<f32>())
+            .fold(0.0, f32::max)
+            + TABLE_PADDING * 2.0;
+
+        Table {
+            width: (title.width + TABLE_PADDING * 2.0).max(inner_width),
+            height: title.height + inner_height,
+            title,
+            columns,
+            inner_width,
+            inner_height,
+        }
+    }
+}
+
+// a table of the season view, with its title above its content
+struct Table {
+    title: Text,
+    columns: Vec<Column>,
+    width: f32,
+    height: f32,
+    inner_width: f32,
+    inner_height: f32,
+}
+
+struct Column {
+    cells: Vec<Text>,
+    width: f32,
+    align_end: bool,
+    spacing: f32,
+}
+
+impl Table {
+    fn draw(&self, card: &mut Card, pixmap: &mut Pixmap, palette: &Extended, x: f32, y: f32) {
+        let outer = palette.secondary.strong;
+        let inner = palette.secondary.weak;
+        fill_rounded(pixmap, x, y, self.width, self.height, outer.color);
+
+        card.draw_text(
+            pixmap,
+            &self.title,
+            x + (self.width - self.title.width) / 2.0,
+            y,
+            outer.text,
+        );
+
+        let x = x + (self.width - self.inner_width) / 2.0;
+        let y = y + self.title.height;
+        fill_rounded(
+            pixmap,
+            x,
+            y,
+            self.inner_width,
+            self.inner_height,
+            inner.color,
+        );
+        if let Some(path) = rounded_rect(
+            x + BORDER / 2.0,
+            y + BORDER / 2.0,
+            self.inner_width - BORDER,
+            self.inner_height - BORDER,
+        ) {
+            pixmap.stroke_path(
+                &path,
+                &paint(outer.color),
+                &Stroke {
+                    width: BORDER,
+                    ..Stroke::default()
+                },
+                Transform::identity(),
+                None,
+            );
+        }
+
+        let mut column_x = x + TABLE_PADDING;
+        for column in &self.columns {
+            column_x += column.spacing;
+            let mut cell_y = y + TABLE_PADDING;
+            for cell in &column.cells {
+                let cell_x = if column.align_end {
+                    column_x + column.width - cell.width
+                } else {
+                    column_x
+                };
+                card.draw_text(pixmap, cell, cell_x, cell_y, inner.text);
+                cell_y += cell.height;
+            }
+            column_x += column.width;
+        }
+    }
+}
+
+// a line of text shaped with one of the bundled fonts
+struct Text {
+    buffer: Buffer,
+    width: f32,
+    height: f32,
+}
+
+struct Card {
+    fonts: FontSystem,
+    cache: SwashCache,
+}
+
+impl Card {
+    // only the bundled fonts are loaded, so the card looks the same on every system
+    fn new() -> Card {
+        let mut db = cosmic_text::fontdb::Database::new();
+        db.load_font_data(crate::F1_FONT.to_vec());
+        db.load_font_data(crate::MONOSPACE_FONT.to_vec());
+
+        Card {
+            fonts: FontSystem::new_with_locale_and_db(String::from("en-US"), db),
+            cache: SwashCache::new(),
+        }
+    }
+
+    fn text(&mut self, text: &str, font: Font, size: f32) -> Text {
+        let attrs = match font {
+            Font::F1 => Attrs::new().family(Family::Name("Formula1")),
+            Font::Mono => Attrs::new()
+                .family(Family::Name("IBM Plex Mono"))
+                .weight(Weight::BOLD),
+        };
+
+        let mut buffer = Buffer::new(&mut self.fonts, Metrics::new(size, size * LINE_HEIGHT));
+        buffer.set_size(&mut self.fonts, None, None);
+        buffer.set_text(&mut self.fonts, text, attrs, Shaping::Advanced);
+        buffer.shape_until_scroll(&mut self.fonts, false);
+
+        let width = buffer
+            .layout_runs()
+            .map(|run| run.line_w)
+            .fold(0.0, f32::max);
+        Text {
+            buffer,
+            width,
+            height: size * LINE_HEIGHT,
+        }
+    }
+
+    fn draw_text(&mut self, pixmap: &mut Pixmap, text: &Text, x: f32, y: f32, color: Color) {
+        let [r, g, b, a] = color.into_rgba8();
+        text.buffer.draw(
+            &mut self.fonts,
+            &mut self.cache,
+            cosmic_text::Color::rgba(r, g, b, a),
+            |px, py, w, h, color| {
+                let Some(rect) = Rect::from_xywh(
+                    x.round() + px as f32,
+                    y.round() + py as f32,
+                    w as f32,
+                    h as f32,
+                ) else {
+                    return;
+                };
+                let mut paint = Paint::default();
+                paint.set_color_rgba8(color.r(), color.g(), color.b(), color.a());
+                pixmap.fill_rect(rect, &paint, Transform::identity(), None);
+            },
+        );
+    }
+}
+
+fn skia_color(color: Color) -> tiny_skia::Color {
+    let [r, g, b, a] = color.into_rgba8();
+    tiny_skia::Color::from_rgba8(r, g, b, a)
+}
+
+fn paint(color: Color) -> Paint<'static> {
+    let mut paint = Paint::default();
+    paint.set_color(skia_color(color));
+    paint.anti_alias = true;
+    paint
+}
+
+fn fill_rounded(pixmap: &mut Pixmap, x: f32, y: f32, width: f32, height: f32, color: Color) {
+    if let Some(path) = rounded_rect(x, y, width, height) {
+        pixmap.fill_path(
+            &path,
+            &paint(color),
+            FillRule::Winding,
+            Transform::identity(),
+            None,
+        );
+    }
+}
+
+fn rounded_rect(x: f32, y: f32, width: f32, height: f32) -> Option<Path> {
+    let r = RADIUS.min(width / 2.0).min(height / 2.0);
+    let mut pb = PathBuilder::new();
+    pb.move_to(x + r, y);
+    pb.line_to(x + width - r, y);
+    pb.quad_to(x + width, y, x + width, y + r);
+    pb.line_to(x + width, y + height - r);
+    pb.quad_to(x + width, y + height, x + width - r, y + height);
+    pb.line_to(x + r, y + height);
+    pb.quad_to(x, y + height, x, y + height - r);
+    pb.line_to(x, y + r);
+    pb.quad_to(x, y, x + r, y);
+    pb.close();
+    pb.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fantasy_season::report::TeamStanding;
+
+    fn report(teams: &[(&str, Option<i16>, i16)]) -> RoundReport {
+        RoundReport {
+            season: String::from("Office League"),
+            year: 2024,
+            round: 3,
+            teams: teams
+                .iter()
+                .map(|(name, round_points, total_points)| TeamStanding {
+                    name: name.to_string(),
+                    round_points: *round_points,
+                    total_points: *total_points,
+                    lineup: None,
+                })
+                .collect(),
+            drivers: Vec::new(),
+        }
+    }
+
+    fn names(rows: Vec<(String, String)>) -> Vec<String> {
+        rows.into_iter().map(|(team, _)| team).collect()
+    }
+
+    #[test]
+    fn teams_not_scored_this_round_are_left_out() {
+        let report = report(&[("Alpha", Some(10), 30), ("Retired", None, 50)]);
+        assert_eq!(
+            round_points(&report),
+            [(String::from("Alpha"), String::from("10"))]
+        );
+    }
+
+    #[test]
+    fn ties_go_to_the_season_total_and_then_the_name() {
+        let report = report(&[
+            ("Delta", Some(10), 20),
+            ("Charlie", Some(10), 20),
+            ("Bravo", Some(10), 40),
+            ("Alpha", Some(5), 90),
+        ]);
+        assert_eq!(
+            names(round_points(&report)),
+            ["Bravo", "Charlie", "Delta", "Alpha"]
+        );
+    }
+
+    #[test]
+    fn an_unscored_round_says_so() {
+        let report = report(&[("Alpha", None, 0), ("Bravo", None, 0)]);
+        assert_eq!(names(round_points(&report)), ["not yet scored"]);
+    }
+}
@@ -0,0 +1,483 @@
+use super::{HTML_STYLE, html_escape, html_table_raw};
+use crate::api::ScheduledRace;
+use crate::fantasy_season::FantasySeason;
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+const SITE_STYLE: &str = "a{color:#ffbd00}nav{margin-bottom:1em}nav a{margin-right:1em}";
+
+// writes a season to a directory of linked pages: an index with the latest standings, the
+// standings of every round that has been started, a page for every team and driver, and a results
+// calendar. Returns the number of pages written
+pub fn write_site(
+    dir: &Path,
+    season: &FantasySeason,
+    schedule: Option<&HashMap<u8, ScheduledRace>>,
+) -> std::io::Result<usize> {
+    std::fs::create_dir_all(dir)?;
+    let site = Site::new(season, schedule);
+
+    let mut pages = vec![
+        (String::from("index.html"), site.index()),
+        (String::from("calendar.html"), site.calendar()),
+    ];
+    pages.extend((1..=site.last_round).map(|round| (Site::round_page(round), site.round(round))));
+    pages.extend(
+        site.teams
+            .iter()
+            .enumerate()
+            .map(|(idx, team)| (Site::team_page(idx), site.team(team))),
+    );
+    pages.extend(
+        site.drivers
+            .iter()
+            .map(|driver| (Site::driver_page(*driver), site.driver(*driver))),
+    );
+
+    for (name, page) in &pages {
+        std::fs::write(dir.join(name), page)?;
+    }
+
+    // pages written before for rounds, teams, or drivers the season no longer has
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        if is_site_page(&name) && !pages.iter().any(|(page, _)| *page == name) {
+            std::fs::remove_file(entry.path())?;
+        }
+    }
+    Ok(pages.len())
+}
+
+// whether a file has the name of a page the site is made of, so other files in the directory are
+// left alone
+fn is_site_page(name: &str) -> bool {
+    let Some(name) = name.strip_suffix(".html") else {
+        return false;
+    };
+    let numbered = |prefix: &str| {
+        name.strip_prefix(prefix)
+            .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+    };
+    name == "index"
+        || name == "calendar"
+        || ["round-", "team-", "driver-"].into_iter().any(numbered)
+}
+
+struct Site<'a> {
+    season: &'a FantasySeason,
+    schedule: Option<&'a HashMap<u8, ScheduledRace>>,
+    teams: Vec<String>,
+    drivers: BTreeSet<u8>,
+    // the last round that has been drafted, downloaded, or scored
+    last_round: u8,
+}
+
+impl<'a> Site<'a> {
+    fn new(
+        season: &'a FantasySeason,
+        schedule: Option<&'a HashMap<u8, ScheduledRace>>,
+    ) -> Site<'a> {
+        let last_round = (1..=u8::MAX)
+            .rev()
+            .find(|r| season.get_status_at(*r) != (false, false, false))
+            .unwrap_or(1);
+        let teams = season.get_team_names();
+
+        // every driver with results, and every driver that has been in a lineup
+        let mut drivers: BTreeSet<u8> = season
+            .get_driver_performance_by(last_round)
+            .into_keys()
+            .collect();
+        for team in &teams {
+            let report = season.get_team_report(team).expect("team came from season");
+            for round in report.rounds {
+                drivers.extend(round.driver_points.into_iter().map(|(d, _)| d));
+            }
+        }
+
+        Site {
+            season,
+            schedule,
+            teams,
+            drivers,
+            last_round,
+        }
+    }
+
+    fn round_page(round: u8) -> String {
+        format!("round-{round}.html")
+    }
+
+    fn team_page(idx: usize) -> String {
+        format!("team-{}.html", idx + 1)
+    }
+
+    fn driver_page(driver: u8) -> String {
+        format!("driver-{driver:02}.html")
+    }
+
+    fn round_link(&self, round: u8) -> String {
+        if round <= self.last_round {
+            format!("<a href=\"{}\">{round}</a>", Site::round_page(round))
+        } else {
+            round.to_string()
+        }
+    }
+
+    fn team_link(&self, team: &str) -> String {
+        match self.teams.iter().position(|t| t == team) {
+            Some(idx) => format!(
+                "<a href=\"{}\">{}</a>",
+                Site::team_page(idx),
+                html_escape(team)
+            ),
+            None => html_escape(team),
+        }
+    }
+
+    fn driver_link(&self, driver: u8) -> String {
+        format!("<a href=\"{}\">{driver:02}</a>", Site::driver_page(driver))
+    }
+
+    fn lineup_links(&self, lineup: &[u8]) -> String {
+        lineup
+            .iter()
+            .map(|d| self.driver_link(*d))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn page(&self, title: &str, body: &str) -> String {
+        let season = html_escape(&format!(
+            "{} {}",
+            self.season.get_name(),
+            self.season.get_season()
+        ));
+        format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>{season} {}</title>\n<style>{HTML_STYLE}{SITE_STYLE}</style>\n</head>\n<body>\n\
+             <nav><a href=\"index.html\">{season}</a><a href=\"calendar.html\">calendar</a></nav>\n\
+             <h1>{}</h1>\n{body}</body>\n</html>\n",
+            html_escape(title),
+            html_escape(title),
+        )
+    }
+
+    // the standings and driver performance after a round
+    fn round_tables(&self, round: u8) -> String {
+        let report = self.season.get_round_report(round);
+        let standings = report
+            .teams
+            .iter()
+            .enumerate()
+            .map(|(pos, team)| {
+                vec![
+                    (pos + 1).to_string(),
+                    self.team_link(&team.name),
+                    team.round_points.map(|p| p.to_string()).unwrap_or_default(),
+                    team.total_points.to_string(),
+                    team.lineup
+                        .as_ref()
+                        .map(|l| self.lineup_links(l))
+                        .unwrap_or_default(),
+                ]
+            })
+            .collect::<Vec<_>>();
+        let drivers = report
+            .drivers
+            .iter()
+            .map(|d| {
+                vec![
+                    self.driver_link(d.driver),
+                    d.round_points.map(|p| p.to_string()).unwrap_or_default(),
+                    d.total_points.to_string(),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        format!(
+            "<h2>standings</h2>\n{}<h2>driver performance</h2>\n{}",
+            html_table_raw(&super::TEAM_HEADER, &standings),
+            html_table_raw(&super::DRIVER_HEADER, &drivers),
+        )
+    }
+
+    fn index(&self) -> String {
+        let latest = (1..=self.last_round)
+            .rev()
+            .find(|r| self.season.get_status_at(*r).2)
+            .unwrap_or(1);
+
+        let mut body = String::new();
+        if let Some(champion) = self.season.get_champion() {
+            body.push_str(&format!("<p>champion: {}</p>\n", self.team_link(&champion)));
+        }
+        body.push_str(&format!("<p>standings after round {latest}</p>\n"));
+        body.push_str(&self.round_tables(latest));
+        body.push_str(&format!(
+            "<h2>rounds</h2>\n<p>{}</p>\n<h2>teams</h2>\n<p>{}</p>\n<h2>drivers</h2>\n<p>{}</p>\n",
+            (1..=self.last_round)
+                .map(|r| self.round_link(r))
+                .collect::<Vec<_>>()
+                .join(" "),
+            self.teams
+                .iter()
+                .map(|t| self.team_link(t))
+                .collect::<Vec<_>>()
+                .join(", "),
+            self.drivers
+                .iter()
+                .map(|d| self.driver_link(*d))
+                .collect::<Vec<_>>()
+                .join(" "),
+        ));
+
+        self.page("standings", &body)
+    }
+
+    fn round(&self, round: u8) -> String {
+        let mut body = String::new();
+        if let Some(race) = self.schedule.and_then(|s| s.get(&round)) {
+            body.push_str(&format!(
+                "<p>{}, {}</p>\n",
+                html_escape(&race.name),
+                format_date(race)
+            ));
+        }
+
+        let mut nav = Vec::new();
+        if round > 1 {
+            nav.push(format!(
+                "<a href=\"{}\">previous round</a>",
+                Site::round_page(round - 1)
+            ));
+        }
+        if round < self.last_round {
+            nav.push(format!(
+                "<a href=\"{}\">next round</a>",
+                Site::round_page(round + 1)
+            ));
+        }
+        body.push_str(&format!("<nav>{}</nav>\n", nav.join("")));
+        body.push_str(&self.round_tables(round));
+
+        self.page(&format!("round {round}"), &body)
+    }
+
+    fn team(&self, team: &str) -> String {
+        let report = self
+            .season
+            .get_team_report(team)
+            .expect("team came from season");
+
+        let mut body = String::new();
+        if let Some((round, points)) = report.best_round {
+            body.push_str(&format!(
+                "<p>best round: {} with {points} points</p>\n",
+                self.round_link(round)
+            ));
+        }
+        if let Some((round, points)) = report.worst_round {
+            body.push_str(&format!(
+                "<p>worst round: {} with {points} points</p>\n",
+                self.round_link(round)
+            ));
+        }
+
+        let rounds = report
+            .rounds
+            .iter()
+            .map(|r| {
+                vec![
+                    self.round_link(r.round),
+                    r.driver_points
+                        .iter()
+                        .map(|(d, p)| match p {
+                            Some(p) => format!("{} ({p})", self.driver_link(*d)),
+                            None => self.driver_link(*d),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                    r.points.map(|p| p.to_string()).unwrap_or_default(),
+                    r.total.to_string(),
+                    r.rank.map(|p| p.to_string()).unwrap_or_default(),
+                ]
+            })
+            .collect::<Vec<_>>();
+        body.push_str("<h2>rounds</h2>\n");
+        body.push_str(&html_table_raw(
+            &["round", "lineup", "points", "total points", "position"],
+            &rounds,
+        ));
+
+        let changes = report
+            .lineup_changes
+            .iter()
+            .map(|c| {
+                vec![
+                    self.round_link(c.round),
+                    self.lineup_links(&c.added),
+                    self.lineup_links(&c.removed),
+                ]
+            })
+            .collect::<Vec<_>>();
+        body.push_str("<h2>drafting history</h2>\n");
+        body.push_str(&html_table_raw(&["round", "drafted", "released"], &changes));
+
+        self.page(team, &body)
+    }
+
+    fn driver(&self, driver: u8) -> String {
+        let report = self.season.get_driver_report(driver);
+
+        let mut body = String::new();
+        if let Some(average) = report.average_finish {
+            body.push_str(&format!("<p>average finish: {average:.1}</p>\n"));
+        }
+        body.push_str(&format!(
+            "<p>positions gained: {}</p>\n",
+            report.positions_gained
+        ));
+        if let Some(points) = report.points_per_round_owned {
+            body.push_str(&format!("<p>points per round owned: {points:.1}</p>\n"));
+        }
+
+        let rounds = report
+            .rounds
+            .iter()
+            .map(|r| {
+                vec![
+                    self.round_link(r.round),
+                    r.qualifying_position.to_string(),
+                    r.grid_position.to_string(),
+                    r.final_position.to_string(),
+                    r.points.to_string(),
+                    r.owners
+                        .iter()
+                        .map(|t| self.team_link(t))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ]
+            })
+            .collect::<Vec<_>>();
+        body.push_str(&html_table_raw(
+            &[
+                "round",
+                "qualifying",
+                "grid",
+                "finish",
+                "points",
+                "owned by",
+            ],
+            &rounds,
+        ));
+
+        self.page(&format!("driver {driver:02}"), &body)
+    }
+
+    // every round in the schedule, or if it could not be downloaded, every round that has been
+    // started
+    fn calendar(&self) -> String {
+        let mut rounds: Vec<u8> = match self.schedule {
+            Some(schedule) => schedule.keys().copied().collect(),
+            None => (1..=self.last_round).collect(),
+        };
+        rounds.sort();
+
+        let rows = rounds
+            .into_iter()
+            .map(|round| {
+                let race = self.schedule.and_then(|s| s.get(&round));
+                let status = match self.season.get_status_at(round) {
+                    (_, _, true) => "scored",
+                    (_, true, _) => "results downloaded",
+                    (true, _, _) => "drafted",
+                    _ => "not started",
+                };
+                vec![
+                    self.round_link(round),
+                    race.map(|r| html_escape(&r.name)).unwrap_or_default(),
+                    race.map(|r| html_escape(&r.circuit)).unwrap_or_default(),
+                    race.map(|r| html_escape(&r.country)).unwrap_or_default(),
+                    race.map(format_date).unwrap_or_default(),
+                    String::from(status),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let mut body = String::new();
+        if self.schedule.is_none() {
+            body.push_str("<p>the race schedule could not be downloaded</p>\n");
+        }
+        body.push_str(&html_table_raw(
+            &["round", "race", "circuit", "country", "date", "status"],
+            &rows,
+        ));
+        self.page("calendar", &body)
+    }
+}
+
+fn format_date(race: &ScheduledRace) -> String {
+    format!(
+        "{}-{:02}-{:02}",
+        race.date.year(),
+        race.date.month() as u8,
+        race.date.day()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fantasy_season::draft::{DraftChoice, DraftSchedule, RollOnChoice};
+    use crate::fantasy_season::score::ScoreChoice;
+
+    #[test]
+    fn pages_from_an_earlier_site_are_removed() {
+        let dir = std::env::temp_dir().join(format!("f1fm-{}-site", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        for name in [
+            "round-9.html",
+            "team-7.html",
+            "driver-44.html",
+            "notes.html",
+            "round-x.html",
+        ] {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+
+        let season = FantasySeason::new(
+            String::from("Office League"),
+            ScoreChoice::default(),
+            DraftChoice::default(),
+            RollOnChoice::default(),
+            DraftSchedule::default(),
+            [String::from("Alpha")],
+            2,
+            2024,
+            20,
+            true,
+        );
+        assert_eq!(write_site(&dir, &season, None).unwrap(), 4);
+
+        let mut files: Vec<_> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        files.sort();
+        assert_eq!(
+            files,
+            [
+                "calendar.html",
+                "index.html",
+                "notes.html",
+                "round-1.html",
+                "round-x.html",
+                "team-1.html"
+            ]
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
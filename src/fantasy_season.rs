@@ -1,24 +1,53 @@
+pub mod catch_up;
 pub mod draft;
 pub mod error;
+pub mod history;
+pub mod legacy;
+pub mod notification;
 pub mod race_results;
+pub mod report;
 pub mod score;
+pub mod settings;
 mod status;
 mod team;
 
-use draft::{DraftChoice, Drafter};
-use error::{DeleteError, DownloadError, DraftError, ScoreError};
+use catch_up::{CatchUpReport, CatchUpStop};
+use draft::{DraftChoice, DraftSchedule, Drafter, RollOn, RollOnChoice, Skip};
+use error::{
+    DeleteError, DownloadError, DraftError, ResultsError, ScoreError, SettingsError, TeamError,
+    WebhookError,
+};
+use history::{HistoryEntry, HistoryEvent};
+use notification::{Notification, NotificationMessage, Webhook};
 use race_results::{DriverResult, RaceResults};
-use score::ScoreChoice;
+use report::{
+    DriverReport, DriverRoundReport, DriverStanding, LineupChange, RoundReport, TeamReport,
+    TeamRoundReport, TeamStanding,
+};
+use score::{AlternateScore, ScoreChange, ScoreChoice};
 use serde::{Deserialize, Serialize};
+use settings::SeasonSettings;
 use status::TeamStatus;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
 use team::Team;
 
 use crate::fantasy_season::score::Scorer;
 
+// a team's total points and standings position, starting from 1, after a round
+#[derive(Debug, Copy, Clone)]
+pub struct Standing {
+    pub round: u8,
+    pub points: i16,
+    pub position: usize,
+}
+
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Serialize, Deserialize)]
 pub struct FantasySeason {
+    // never changes, so leagues can find the season after it is renamed
+    id: u64,
     name: String,
     teams: Vec<Team>,
     results: HashMap<u8, RaceResults>,
@@ -29,6 +58,13 @@ pub struct FantasySeason {
     season: u16,
     grid_size: u8,
     enforce_uniqueness: bool,
+    history: Vec<HistoryEntry>,
+    round_count: Option<u8>,
+    score_changes: Vec<ScoreChange>,
+    lineup_sizes: BTreeMap<u8, u8>,
+    roll_on_choice: RollOnChoice,
+    draft_schedule: DraftSchedule,
+    webhooks: Vec<Webhook>,
 }
 
 impl FantasySeason {
@@ -37,6 +73,8 @@ impl FantasySeason {
         name: String,
         score_choice: ScoreChoice,
         draft_choice: DraftChoice,
+        roll_on_choice: RollOnChoice,
+        draft_schedule: DraftSchedule,
         starting_teams: I,
         lineup_size: u8,
         season: u16,
@@ -49,6 +87,7 @@ impl FantasySeason {
         let status = TeamStatus::new();
 
         FantasySeason {
+            id: new_season_id(),
             name,
             teams,
             results,
@@ -59,9 +98,20 @@ impl FantasySeason {
             season,
             grid_size,
             enforce_uniqueness,
+            history: Vec::new(),
+            round_count: None,
+            score_changes: Vec::new(),
+            lineup_sizes: BTreeMap::new(),
+            roll_on_choice,
+            draft_schedule,
+            webhooks: Vec::new(),
         }
     }
 
+    pub fn get_id(&self) -> u64 {
+        self.id
+    }
+
     pub fn get_name(&self) -> &str {
         self.name.as_str()
     }
@@ -80,12 +130,91 @@ impl FantasySeason {
         }
 
         self.results.insert(round, race_results);
+        self.log(round, HistoryEvent::ResultsAdded);
+        Ok(())
+    }
+
+    pub fn get_results_at(&self, round: u8) -> Option<&RaceResults> {
+        self.results.get(&round)
+    }
+
+    // replaces a round's results with ones entered by hand, rescoring the season if the round has
+    // been scored. Nothing is changed if the results are invalid or the season cannot be rescored
+    pub fn set_manual_results(
+        &mut self,
+        round: u8,
+        results: Vec<(u8, DriverResult)>,
+    ) -> Result<(), ResultsError> {
+        let results = RaceResults::manual(results, self.grid_size)?;
+        let changes = match self.results.get(&round) {
+            Some(previous) => results_changes(&previous.drivers, &results.drivers),
+            None => Vec::new(),
+        };
+
+        let previous = self.results.insert(round, results);
+        if self.team_status.has_scored(round)
+            && let Err(err) = self.rescore()
+        {
+            match previous {
+                Some(previous) => self.results.insert(round, previous),
+                None => self.results.remove(&round),
+            };
+            return Err(ResultsError::RescoreFailed(err));
+        }
+
+        self.log(round, HistoryEvent::ResultsEdited(changes));
         Ok(())
     }
 
-    #[allow(dead_code)]
+    // the score mode used for rounds that have not been scored yet
     pub fn get_score_choice(&self) -> ScoreChoice {
-        self.score_choice
+        self.get_score_choice_at(u8::MAX)
+    }
+
+    // the score mode a round is scored with, taking every change into account in the order they
+    // were made
+    pub fn get_score_choice_at(&self, round: u8) -> ScoreChoice {
+        self.score_changes
+            .iter()
+            .rfind(|c| c.retroactive || c.round <= round)
+            .map(|c| c.choice)
+            .unwrap_or(self.score_choice)
+    }
+
+    pub fn get_score_changes(&self) -> &[ScoreChange] {
+        &self.score_changes
+    }
+
+    // switches to a new score mode from a round onward, or for the whole season if retroactive,
+    // and rescores every round the change affects. Nothing is changed if any round fails to rescore
+    pub fn change_score_choice(
+        &mut self,
+        round: u8,
+        choice: ScoreChoice,
+        retroactive: bool,
+    ) -> Result<(), ScoreError> {
+        let previous = self.get_score_choice_at(round);
+        self.score_changes.push(ScoreChange {
+            round,
+            choice,
+            retroactive,
+        });
+
+        if let Err(err) = self.rescore() {
+            self.score_changes.pop();
+            return Err(err);
+        }
+
+        self.log(
+            round,
+            HistoryEvent::ScoreChoiceChanged {
+                from: previous,
+                to: choice,
+                retroactive,
+                totals: self.get_points_by(u8::MAX),
+            },
+        );
+        Ok(())
     }
 
     pub fn score(&mut self, round: u8) -> Result<(), ScoreError> {
@@ -102,19 +231,107 @@ impl FantasySeason {
             .ok_or(ScoreError::RoundResultsDoNotExist(round))?
             .drivers;
         let mut points = Vec::with_capacity(self.teams.len());
-        for team in &self.teams {
+        for team in self.teams.iter().filter(|t| t.is_active(round)) {
             points.push(team.calculate_score(
                 round,
                 self.grid_size,
-                &self.score_choice,
+                &self.get_score_choice_at(round),
                 driver_results,
             )?);
         }
-        for team in &mut self.teams {
+        for team in self.teams.iter_mut().filter(|t| t.is_active(round)) {
             team.store_score(round, points.remove(0));
         }
 
         self.team_status.toggle_scored(round);
+        self.log(round, HistoryEvent::Scored(self.scores_at(round)));
+        Ok(())
+    }
+
+    // the current settings, with the lineup size of a round
+    pub fn get_settings(&self, round: u8) -> SeasonSettings {
+        SeasonSettings {
+            name: self.name.clone(),
+            team_names: self.get_team_names(),
+            lineup_size: self.get_lineup_size_at(round),
+            grid_size: self.grid_size,
+            draft_choice: self.draft_choice,
+            roll_on_choice: self.roll_on_choice,
+            draft_schedule: self.draft_schedule.clone(),
+            enforce_uniqueness: self.enforce_uniqueness,
+        }
+    }
+
+    // applies new settings, rescoring the season if the grid size changed. Nothing is changed if
+    // the settings are invalid or do not fit the lineups already drafted
+    pub fn change_settings(
+        &mut self,
+        round: u8,
+        settings: SeasonSettings,
+    ) -> Result<(), SettingsError> {
+        if settings.name.trim().is_empty() {
+            return Err(SettingsError::EmptySeasonName);
+        }
+        if settings.team_names.len() != self.teams.len() {
+            return Err(SettingsError::WrongTeamCount);
+        }
+        let mut names = HashSet::new();
+        for name in &settings.team_names {
+            if name.trim().is_empty() {
+                return Err(SettingsError::EmptyTeamName);
+            }
+            if !names.insert(name) {
+                return Err(SettingsError::DuplicateTeamName(name.clone()));
+            }
+        }
+        if settings.grid_size == 0 {
+            return Err(SettingsError::ZeroGridSize);
+        }
+        if settings.enforce_uniqueness && !self.enforce_uniqueness {
+            for r in (1..=u8::MAX).filter(|r| self.team_status.has_drafted(*r)) {
+                let mut already_seen = HashSet::new();
+                for driver in self.teams.iter().flat_map(|t| t.get_lineup_at(r)).flatten() {
+                    if !already_seen.insert(driver) {
+                        return Err(SettingsError::RoundLineupNonUnique(r, driver));
+                    }
+                }
+            }
+        }
+
+        let lineup_size_changed = settings.lineup_size != self.get_lineup_size_at(round);
+        if lineup_size_changed {
+            self.check_lineup_size(round, settings.lineup_size)
+                .map_err(SettingsError::LineupSize)?;
+        }
+
+        let changes = self.get_settings(round).changes(&settings);
+
+        if settings.grid_size != self.grid_size {
+            let previous = self.grid_size;
+            self.grid_size = settings.grid_size;
+            if let Err(err) = self.rescore() {
+                self.grid_size = previous;
+                return Err(SettingsError::RescoreFailed(err));
+            }
+        }
+
+        self.name = settings.name;
+        for (team, name) in self.teams.iter_mut().zip(settings.team_names) {
+            team.set_name(name);
+        }
+        self.draft_choice = settings.draft_choice;
+        self.roll_on_choice = settings.roll_on_choice;
+        self.draft_schedule = settings.draft_schedule;
+        self.enforce_uniqueness = settings.enforce_uniqueness;
+
+        // the lineup size change is recorded in the history on its own
+        if lineup_size_changed {
+            self.set_lineup_size(round, settings.lineup_size)
+                .expect("lineup size was checked above");
+        }
+        if !changes.is_empty() {
+            self.log(round, HistoryEvent::SettingsChanged(changes));
+        }
         Ok(())
     }
 
@@ -122,47 +339,327 @@ impl FantasySeason {
         self.draft_choice
     }
 
+    // whether drafting a round needs new drivers to be picked, rather than keeping the previous
+    // lineups as skip seasons do
+    pub fn needs_drafter(&self, round: u8) -> bool {
+        self.lineup_grows_at(round)
+            || (self.draft_choice != DraftChoice::Skip && self.draft_schedule.requires_draft(round))
+    }
+
+    pub fn get_roll_on_choice(&self) -> RollOnChoice {
+        self.roll_on_choice
+    }
+
+    // the drivers leaving each lineup when a roll on round is drafted, for the teams still to be
+    // drafted that round. Returns None if the teams choose who leaves themselves
+    pub fn get_roll_on_releases(&self, round: u8) -> Option<HashMap<String, Vec<u8>>> {
+        let lineup_size = self.get_lineup_size_at(round) as usize;
+        let previous_round = round.checked_sub(1)?;
+        let previous_results = self.results.get(&previous_round).map(|r| &r.drivers);
+        let choice = match (self.roll_on_choice, previous_results) {
+            (RollOnChoice::TeamChooses, _) => return None,
+            // without results the lowest scoring driver is unknown, so the last driver leaves
+            (RollOnChoice::DropLowestScoring, None) => RollOnChoice::DropLast,
+            (choice, _) => choice,
+        };
+
+        let releases = self
+            .teams
+            .iter()
+            .filter(|t| t.is_active(round) && !t.has_lineup_at(round))
+            .filter_map(|team| {
+                let mut lineup = team.get_lineup_at(previous_round)?;
+                let count = RollOn::releases_needed(lineup.len(), lineup_size);
+
+                // sorts the lineup so the drivers to release come last, keeping the lineup order
+                // for ties so later drivers leave first
+                match (choice, previous_results) {
+                    (RollOnChoice::DropLowestScoring, Some(results)) => {
+                        let scorer = self.get_score_choice_at(previous_round);
+                        let points = |d: &u8| {
+                            results
+                                .get(d)
+                                .map(|dr| scorer.score(self.grid_size, dr))
+                                .unwrap_or_default()
+                        };
+                        lineup.sort_by_key(|d| std::cmp::Reverse(points(d)));
+                    }
+                    (RollOnChoice::DropLongestHeld, _) => {
+                        let held = |d: &u8| {
+                            (1..=previous_round)
+                                .rev()
+                                .take_while(|r| {
+                                    team.get_lineup_at(*r).is_some_and(|l| l.contains(d))
+                                })
+                                .count()
+                        };
+                        lineup.sort_by_key(held);
+                    }
+                    _ => {}
+                }
+
+                let released = lineup.split_off(lineup.len() - count);
+                Some((team.name(), released))
+            })
+            .collect();
+
+        Some(releases)
+    }
+
     pub fn draft(&mut self, round: u8, df: &mut dyn Drafter) -> Result<(), DraftError> {
         if self.team_status.has_drafted(round) {
             return Err(DraftError::RoundLineupAlreadyExists(round));
         }
 
+        let lineups = self.calculate_lineups(round, df, true)?;
+        self.store_lineups(round, lineups);
+
+        self.team_status.toggle_drafted(round);
+        self.log(round, HistoryEvent::Drafted(self.lineups_at(round)));
+        Ok(())
+    }
+
+    // replaces an already drafted, but unscored, lineup in a single step, so that the history
+    // records the edit alongside the lineup it replaced
+    pub fn replace_lineup(&mut self, round: u8, df: &mut dyn Drafter) -> Result<(), DraftError> {
+        if !self.team_status.has_drafted(round) {
+            return self.draft(round, df);
+        }
+        if self.team_status.has_scored(round) {
+            return Err(DraftError::RoundLineupAlreadyScored(round));
+        }
+
+        let lineups = self.calculate_lineups(round, df, false)?;
+        let before = self.lineups_at(round);
+        self.teams.iter_mut().for_each(|t| t.delete_round(round));
+        self.store_lineups(round, lineups);
+
+        let after = self.lineups_at(round);
+        self.log(round, HistoryEvent::LineupEdited { before, after });
+        Ok(())
+    }
+
+    // the lineups of every team active in a round, along with the index of the team. If keeping
+    // existing lineups, teams that already have one, having joined that round, are not drafted
+    fn calculate_lineups(
+        &self,
+        round: u8,
+        df: &mut dyn Drafter,
+        keep_existing: bool,
+    ) -> Result<Vec<(usize, Vec<u8>)>, DraftError> {
         let mut lineups = Vec::with_capacity(self.teams.len());
-        for team in &self.teams {
-            lineups.push(team.calculate_lineup(round, df)?);
+        let mut kept = Vec::new();
+        for (idx, team) in self.teams.iter().enumerate() {
+            if !team.is_active(round) {
+                continue;
+            }
+            match team.get_lineup_at(round) {
+                Some(lineup) if keep_existing => kept.push(lineup),
+                _ => lineups.push((
+                    idx,
+                    team.calculate_lineup(round, df, self.get_lineup_size_at(round))?,
+                )),
+            }
         }
 
         if self.enforce_uniqueness {
             let mut already_seen = HashSet::new();
-            for lineup in &lineups {
-                for driver in lineup {
-                    if already_seen.contains(&driver) {
-                        return Err(DraftError::RoundDraftNonUnique(round, *driver));
-                    }
-                    already_seen.insert(driver);
+            for driver in lineups
+                .iter()
+                .flat_map(|(_, l)| l)
+                .chain(kept.iter().flatten())
+            {
+                if already_seen.contains(&driver) {
+                    return Err(DraftError::RoundDraftNonUnique(round, *driver));
                 }
+                already_seen.insert(driver);
             }
         }
 
-        for team in &mut self.teams {
-            team.store_lineup(round, lineups.remove(0));
+        Ok(lineups)
+    }
+
+    fn store_lineups(&mut self, round: u8, lineups: Vec<(usize, Vec<u8>)>) {
+        for (idx, lineup) in lineups {
+            self.teams[idx].store_lineup(round, lineup);
         }
+    }
 
-        self.team_status.toggle_drafted(round);
+    // adds a team that joins from a round onward with its first lineup, starting with a number of
+    // points. Rounds before it joined do not count towards its standings
+    pub fn add_team(
+        &mut self,
+        name: String,
+        round: u8,
+        lineup: Vec<u8>,
+        handicap: i16,
+    ) -> Result<(), TeamError> {
+        if name.trim().is_empty() {
+            return Err(TeamError::EmptyTeamName);
+        }
+        if self.teams.iter().any(|t| t.name() == name) {
+            return Err(TeamError::DuplicateTeamName(name));
+        }
+        let lineup_size = self.get_lineup_size_at(round);
+        if lineup.len() != lineup_size as usize {
+            return Err(TeamError::WrongLineupSize(lineup_size));
+        }
+        if self.team_status.has_scored(round) {
+            return Err(TeamError::RoundAlreadyScored(round));
+        }
+        if let Some(drafted) = (round..=u8::MAX)
+            .skip(1)
+            .find(|r| self.team_status.has_drafted(*r))
+        {
+            return Err(TeamError::LaterRoundDrafted(drafted));
+        }
+        if self.enforce_uniqueness {
+            let taken: HashSet<u8> = self
+                .teams
+                .iter()
+                .flat_map(|t| t.get_lineup_at(round))
+                .flatten()
+                .collect();
+            let mut already_seen = HashSet::new();
+            for driver in &lineup {
+                if taken.contains(driver) || !already_seen.insert(driver) {
+                    return Err(TeamError::LineupNonUnique(round, *driver));
+                }
+            }
+        }
+
+        self.teams
+            .push(Team::joining(name.clone(), round, lineup.clone(), handicap));
+        self.log(
+            round,
+            HistoryEvent::TeamAdded {
+                team: name,
+                lineup,
+                handicap,
+            },
+        );
         Ok(())
     }
 
+    // stops a team from taking part from a round onward. It keeps the points it scored before
+    pub fn retire_team(&mut self, name: &str, round: u8) -> Result<(), TeamError> {
+        let team = self
+            .teams
+            .iter()
+            .find(|t| t.name() == name)
+            .ok_or(TeamError::TeamDoesNotExist(name.to_string()))?;
+        if team.get_retired().is_some() {
+            return Err(TeamError::TeamAlreadyRetired(name.to_string()));
+        }
+        if round <= team.get_joined() {
+            return Err(TeamError::RetireBeforeJoining(name.to_string()));
+        }
+        if let Some(scored) = (round..=u8::MAX).find(|r| self.team_status.has_scored(*r)) {
+            return Err(TeamError::RoundAlreadyScored(scored));
+        }
+
+        self.teams
+            .iter_mut()
+            .find(|t| t.name() == name)
+            .expect("team was found above")
+            .retire(round);
+        self.log(round, HistoryEvent::TeamRetired(name.to_string()));
+        Ok(())
+    }
+
+    // the teams still to be drafted in a round, in creation order
+    pub fn get_drafting_team_names(&self, round: u8) -> Vec<String> {
+        self.teams
+            .iter()
+            .filter(|t| t.is_active(round) && !t.has_lineup_at(round))
+            .map(Team::name)
+            .collect()
+    }
+
+    // every team with the round it joined and the round it retired, if it has
+    pub fn get_team_memberships(&self) -> Vec<(String, u8, Option<u8>)> {
+        self.teams
+            .iter()
+            .map(|t| (t.name(), t.get_joined(), t.get_retired()))
+            .collect()
+    }
+
+    // drafts and scores every round in order, starting from the first unscored round, until a
+    // round needs input from the league manager or has no results yet
+    pub fn catch_up(&mut self) -> CatchUpReport {
+        let mut report = CatchUpReport::new();
+
+        let mut round = self.get_first_unscored_round();
+        loop {
+            if self.round_count.is_some_and(|count| round > count) {
+                break;
+            }
+
+            if !self.team_status.has_drafted(round) {
+                if self.needs_drafter(round) {
+                    report.stopped = CatchUpStop::NeedsDraft(round);
+                    break;
+                }
+                if let Err(de) = self.draft(round, &mut Skip::new()) {
+                    report.stopped = CatchUpStop::DraftFailed(round, de);
+                    break;
+                }
+                report.drafted.push(round);
+            }
+
+            if !self.results.contains_key(&round) {
+                report.stopped = CatchUpStop::NoResults(round);
+                break;
+            }
+
+            if let Err(se) = self.score(round) {
+                report.stopped = CatchUpStop::ScoreFailed(round, se);
+                break;
+            }
+            report.scored.push(round);
+
+            match round.checked_add(1) {
+                Some(next) => round = next,
+                None => break,
+            }
+        }
+
+        report
+    }
+
+    // every round from the first unscored round onwards that does not have results yet
+    pub fn get_rounds_without_results(&self) -> Vec<u8> {
+        (self.get_first_unscored_round()..=self.round_count.unwrap_or(u8::MAX))
+            .filter(|round| !self.results.contains_key(round))
+            .collect()
+    }
+
+    pub fn get_first_unscored_round(&self) -> u8 {
+        (1..=u8::MAX)
+            .find(|round| !self.team_status.has_scored(*round))
+            .unwrap_or(u8::MAX)
+    }
+
     pub fn delete_round(&mut self, round: u8) -> Result<(), DeleteError> {
+        let mut deleted_scores = Vec::new();
         if self.team_status.has_scored(round) {
-            self.teams.iter_mut().for_each(|t| t.delete_score(round));
+            deleted_scores = self.scores_at(round);
+            self.teams
+                .iter_mut()
+                .filter(|t| t.is_active(round))
+                .for_each(|t| t.delete_score(round));
             self.team_status.toggle_scored(round);
         }
 
-        let _ = self
+        let removed = self
             .results
             .remove(&round)
             .ok_or(DeleteError::ResultsDeleteWhenResultsDontExist(round));
 
+        if removed.is_ok() || !deleted_scores.is_empty() {
+            self.log(round, HistoryEvent::RoundDeleted(deleted_scores));
+        }
+
         Ok(())
     }
 
@@ -171,13 +668,19 @@ impl FantasySeason {
             return Err(DeleteError::LineupDeleteWhileScoresExist(round));
         }
 
-        if self.team_status.has_drafted(round + 1) {
+        if self.team_status.has_drafted(round.saturating_add(1)) {
             return Err(DeleteError::LineupDeleteWhenNextRoundDrafted(round));
         }
 
-        self.teams.iter_mut().for_each(|t| t.delete_round(round));
+        // teams that joined this round keep the lineup they joined with
+        let before = self.lineups_at(round);
+        self.teams
+            .iter_mut()
+            .filter(|t| t.get_joined() != round)
+            .for_each(|t| t.delete_round(round));
         if self.team_status.has_drafted(round) {
             self.team_status.toggle_drafted(round);
+            self.log(round, HistoryEvent::LineupDeleted(before));
         }
 
         Ok(())
@@ -196,7 +699,7 @@ impl FantasySeason {
     // as a result, teams in fantasy_season should be added in tiebreaking order, eg: the reverse
     // standings from the previous season
     pub fn get_points_by(&self, round: u8) -> Vec<(String, i16)> {
-        let mut teams: Vec<_> = self.teams.iter().collect();
+        let mut teams: Vec<_> = self.teams.iter().filter(|t| t.has_joined(round)).collect();
         teams.sort_by(|a, b| Team::sort_by(a, b, round));
         teams
             .into_iter()
@@ -204,9 +707,74 @@ impl FantasySeason {
             .collect()
     }
 
+    // for each team in creation order, their standing after every scored round
+    pub fn get_standings_progression(&self) -> Vec<(String, Vec<Standing>)> {
+        let mut progression: Vec<_> = self.teams.iter().map(|t| (t.name(), Vec::new())).collect();
+
+        for round in (1..=u8::MAX).filter(|r| self.team_status.has_scored(*r)) {
+            for (pos, (team, points)) in self.get_points_by(round).into_iter().enumerate() {
+                if let Some((_, rounds)) = progression.iter_mut().find(|(t, _)| *t == team) {
+                    rounds.push(Standing {
+                        round,
+                        points,
+                        position: pos + 1,
+                    });
+                }
+            }
+        }
+
+        progression
+    }
+
+    // the total points up to a round if every scored round had been scored differently, ordered
+    // using the same tiebreakers as get_points_by
+    pub fn get_what_if_points_by(
+        &self,
+        round: u8,
+        alternate: &AlternateScore,
+    ) -> Vec<(String, i16)> {
+        let mut teams: Vec<_> = self
+            .teams
+            .iter()
+            .filter(|team| team.has_joined(round))
+            .map(|team| {
+                let rounds: Vec<i16> = (1..=round)
+                    .filter(|r| self.team_status.has_scored(*r))
+                    .filter_map(|r| {
+                        let drivers = &self.results.get(&r)?.drivers;
+                        let lineup = team.get_lineup_at(r)?;
+                        Some(
+                            lineup
+                                .iter()
+                                .filter_map(|d| drivers.get(d))
+                                .map(|dr| alternate.score(self.grid_size, dr))
+                                .sum(),
+                        )
+                    })
+                    .collect();
+                (team.name(), team.get_handicap(), rounds)
+            })
+            .collect();
+
+        // a stable sort keeps creation order as the final tiebreaker
+        teams.sort_by(|(_, a_handicap, a), (_, b_handicap, b)| {
+            let max = |r: &Vec<i16>| r.iter().max().copied().unwrap_or_default();
+            let min = |r: &Vec<i16>| r.iter().min().copied().unwrap_or_default();
+            (b.iter().sum::<i16>() + b_handicap)
+                .cmp(&(a.iter().sum::<i16>() + a_handicap))
+                .then(max(b).cmp(&max(a)))
+                .then(min(a).cmp(&min(b)))
+        });
+
+        teams
+            .into_iter()
+            .map(|(team, handicap, rounds)| (team, rounds.iter().sum::<i16>() + handicap))
+            .collect()
+    }
+
     pub fn get_points_at(&self, round: u8) -> Option<Vec<(String, i16)>> {
         if self.team_status.has_scored(round) {
-            let mut teams: Vec<_> = self.teams.iter().collect();
+            let mut teams: Vec<_> = self.teams.iter().filter(|t| t.is_active(round)).collect();
             teams.sort_by(|a, b| Team::sort_at(a, b, round));
             Some(
                 teams
@@ -234,8 +802,204 @@ impl FantasySeason {
         map
     }
 
-    pub fn get_lineup_size(&self) -> u8 {
-        self.lineup_size
+    pub fn get_team_report(&self, name: &str) -> Option<TeamReport> {
+        let team = self.teams.iter().find(|t| t.name() == name)?;
+
+        let mut rounds = Vec::new();
+        let mut lineup_changes = Vec::new();
+        let mut previous_lineup: Vec<u8> = Vec::new();
+
+        for round in team.get_rounds() {
+            let lineup = team.get_lineup_at(round).expect("round came from team");
+            let driver_results = self.results.get(&round).map(|r| &r.drivers);
+            let points = team.get_points_at(round);
+
+            let driver_points = lineup
+                .iter()
+                .map(|driver| {
+                    let result = driver_results.and_then(|r| r.get(driver));
+                    (
+                        *driver,
+                        result.map(|dr| self.get_score_choice_at(round).score(self.grid_size, dr)),
+                    )
+                })
+                .collect();
+
+            let rank = points.and_then(|_| {
+                self.get_points_by(round)
+                    .iter()
+                    .position(|(t, _)| t == name)
+                    .map(|pos| pos + 1)
+            });
+
+            let added: Vec<u8> = lineup
+                .iter()
+                .filter(|d| !previous_lineup.contains(d))
+                .copied()
+                .collect();
+            let removed: Vec<u8> = previous_lineup
+                .iter()
+                .filter(|d| !lineup.contains(d))
+                .copied()
+                .collect();
+            if !added.is_empty() || !removed.is_empty() {
+                lineup_changes.push(LineupChange {
+                    round,
+                    added,
+                    removed,
+                });
+            }
+            previous_lineup = lineup;
+
+            rounds.push(TeamRoundReport {
+                round,
+                driver_points,
+                points,
+                total: team.get_points_by(round),
+                rank,
+            });
+        }
+
+        let scored = || rounds.iter().filter_map(|r| r.points.map(|p| (r.round, p)));
+        let best_round = scored().max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)));
+        let worst_round = scored().min_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
+
+        Some(TeamReport {
+            name: team.name(),
+            rounds,
+            best_round,
+            worst_round,
+            lineup_changes,
+        })
+    }
+
+    // the standings, lineups, and driver performance of a round, as shown on the main view
+    pub fn get_round_report(&self, round: u8) -> RoundReport {
+        let points_at = self.get_points_at(round);
+        let lineups = self.get_lineup_at(round);
+        let teams = self
+            .get_points_by(round)
+            .into_iter()
+            .map(|(name, total_points)| TeamStanding {
+                round_points: points_at
+                    .as_ref()
+                    .and_then(|p| p.iter().find(|(t, _)| *t == name))
+                    .map(|(_, points)| *points),
+                total_points,
+                lineup: lineups.get(&name).cloned(),
+                name,
+            })
+            .collect();
+
+        let driver_points_at = self.get_driver_performance_at(round);
+        let mut drivers: Vec<_> = self
+            .get_driver_performance_by(round)
+            .into_iter()
+            .map(|(driver, total_points)| DriverStanding {
+                driver,
+                round_points: driver_points_at
+                    .as_ref()
+                    .and_then(|p| p.get(&driver).copied()),
+                total_points,
+            })
+            .collect();
+        drivers.sort_by(|a, b| {
+            b.total_points
+                .cmp(&a.total_points)
+                .then(b.driver.cmp(&a.driver))
+        });
+
+        RoundReport {
+            season: self.name.clone(),
+            year: self.season,
+            round,
+            teams,
+            drivers,
+        }
+    }
+
+    pub fn get_driver_report(&self, driver: u8) -> DriverReport {
+        let mut rounds: Vec<_> = self
+            .results
+            .iter()
+            .filter_map(|(round, results)| {
+                results.drivers.get(&driver).map(|dr| DriverRoundReport {
+                    round: *round,
+                    final_position: dr.final_position,
+                    grid_position: dr.grid_position,
+                    qualifying_position: dr.qualifying_position,
+                    points: self.get_score_choice_at(*round).score(self.grid_size, dr),
+                    owners: self
+                        .teams
+                        .iter()
+                        .filter(|t| t.get_lineup_at(*round).is_some_and(|l| l.contains(&driver)))
+                        .map(Team::name)
+                        .collect(),
+                })
+            })
+            .collect();
+        rounds.sort_by_key(|r| r.round);
+
+        let average_finish = (!rounds.is_empty()).then(|| {
+            rounds.iter().map(|r| r.final_position as f32).sum::<f32>() / rounds.len() as f32
+        });
+        // drivers starting from the pit lane have a grid position of 0, so have no places to
+        // compare against
+        let positions_gained = rounds
+            .iter()
+            .filter(|r| r.grid_position != 0)
+            .map(|r| r.grid_position as i16 - r.final_position as i16)
+            .sum();
+
+        let owned: Vec<_> = rounds.iter().filter(|r| !r.owners.is_empty()).collect();
+        let points_per_round_owned = (!owned.is_empty())
+            .then(|| owned.iter().map(|r| r.points as f32).sum::<f32>() / owned.len() as f32);
+
+        DriverReport {
+            driver,
+            rounds,
+            average_finish,
+            positions_gained,
+            points_per_round_owned,
+        }
+    }
+
+    // the number of drivers in each lineup for a round
+    pub fn get_lineup_size_at(&self, round: u8) -> u8 {
+        self.lineup_sizes
+            .range(..=round)
+            .next_back()
+            .map(|(_, size)| *size)
+            .unwrap_or(self.lineup_size)
+    }
+
+    // whether lineups have more drivers in a round than in the round before, so that skipping the
+    // draft is not enough to fill them
+    pub fn lineup_grows_at(&self, round: u8) -> bool {
+        round > 1 && self.get_lineup_size_at(round) > self.get_lineup_size_at(round - 1)
+    }
+
+    // changes the number of drivers in each lineup from a round onward. Rounds that have already
+    // been drafted keep the size they were drafted with
+    pub fn set_lineup_size(&mut self, round: u8, size: u8) -> Result<(), DraftError> {
+        self.check_lineup_size(round, size)?;
+
+        let previous = self.get_lineup_size_at(round);
+        if previous == size {
+            return Ok(());
+        }
+
+        // later changes are replaced, as this one now applies to every round from here on
+        self.lineup_sizes.retain(|r, _| *r < round);
+        self.lineup_sizes.insert(round, size);
+        self.log(
+            round,
+            HistoryEvent::LineupSizeChanged {
+                from: previous,
+                to: size,
+            },
+        );
+        Ok(())
     }
 
     pub fn get_status_at(&self, round: u8) -> (bool, bool, bool) {
@@ -259,7 +1023,7 @@ impl FantasySeason {
                         drivers.get_mut(driver).expect("we just inserted it")
                     };
 
-                    *score += self.score_choice.score(self.grid_size, result)
+                    *score += self.get_score_choice_at(r).score(self.grid_size, result)
                 }
             }
         }
@@ -271,7 +1035,11 @@ impl FantasySeason {
         if let Some(results) = self.results.get(&round) {
             let mut drivers = HashMap::new();
             for (driver, result) in &results.drivers {
-                drivers.insert(*driver, self.score_choice.score(self.grid_size, result));
+                drivers.insert(
+                    *driver,
+                    self.get_score_choice_at(round)
+                        .score(self.grid_size, result),
+                );
             }
             return Some(drivers);
         }
@@ -281,4 +1049,461 @@ impl FantasySeason {
     pub fn enforces_unique(&self) -> bool {
         self.enforce_uniqueness
     }
+
+    // the number of rounds in the season, once the schedule is known
+    pub fn get_round_count(&self) -> Option<u8> {
+        self.round_count
+    }
+
+    pub fn set_round_count(&mut self, round_count: u8) {
+        self.round_count = Some(round_count);
+    }
+
+    pub fn is_final_round(&self, round: u8) -> bool {
+        self.round_count == Some(round)
+    }
+
+    // a season is complete once every round in it has been scored
+    pub fn is_complete(&self) -> bool {
+        self.round_count
+            .is_some_and(|count| (1..=count).all(|round| self.team_status.has_scored(round)))
+    }
+
+    pub fn get_champion(&self) -> Option<String> {
+        if self.is_complete() {
+            self.get_points_by(u8::MAX)
+                .into_iter()
+                .next()
+                .map(|(team, _)| team)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_webhooks(&self) -> &[Webhook] {
+        &self.webhooks
+    }
+
+    pub fn add_webhook(&mut self, webhook: Webhook) -> Result<(), WebhookError> {
+        if self
+            .webhooks
+            .iter()
+            .any(|w| w.get_url() == webhook.get_url())
+        {
+            return Err(WebhookError::DuplicateUrl(webhook.get_url().to_string()));
+        }
+        self.webhooks.push(webhook);
+        Ok(())
+    }
+
+    pub fn remove_webhook(&mut self, idx: usize) {
+        if idx < self.webhooks.len() {
+            self.webhooks.remove(idx);
+        }
+    }
+
+    // what the season's webhooks are told about a notification
+    pub fn get_notification_message(&self, notification: Notification) -> NotificationMessage {
+        NotificationMessage {
+            notification,
+            season: self.name.clone(),
+            year: self.season,
+            text: notification.message(self),
+            report: notification
+                .round()
+                .map(|round| self.get_round_report(round)),
+        }
+    }
+
+    // the history as it was recorded, but with every team under its current name
+    pub fn get_history(&self) -> Vec<HistoryEntry> {
+        history::with_current_names(&self.history, &self.get_team_names())
+    }
+
+    fn check_lineup_size(&self, round: u8, size: u8) -> Result<(), DraftError> {
+        if size == 0 {
+            return Err(DraftError::EmptyLineupSize);
+        }
+        if let Some(drafted) = (round..=u8::MAX).find(|r| self.team_status.has_drafted(*r)) {
+            return Err(DraftError::RoundLineupAlreadyExists(drafted));
+        }
+        Ok(())
+    }
+
+    // recalculates the points of every scored round with the score mode it should now use, only
+    // storing them once every round has been calculated
+    fn rescore(&mut self) -> Result<(), ScoreError> {
+        let mut rounds = Vec::new();
+        for round in (1..=u8::MAX).filter(|r| self.team_status.has_scored(*r)) {
+            let driver_results = &self
+                .results
+                .get(&round)
+                .ok_or(ScoreError::RoundResultsDoNotExist(round))?
+                .drivers;
+            let choice = self.get_score_choice_at(round);
+            let points = self
+                .teams
+                .iter()
+                .filter(|team| team.is_active(round))
+                .map(|team| team.calculate_score(round, self.grid_size, &choice, driver_results))
+                .collect::<Result<Vec<_>, _>>()?;
+            rounds.push((round, points));
+        }
+
+        for (round, points) in rounds {
+            let teams = self.teams.iter_mut().filter(|team| team.is_active(round));
+            for (team, points) in teams.zip(points) {
+                team.store_score(round, points);
+            }
+        }
+        Ok(())
+    }
+
+    fn log(&mut self, round: u8, event: HistoryEvent) {
+        self.history.push(HistoryEntry::new(round, event));
+    }
+
+    // lineups for a round in team creation order, skipping teams without one
+    fn lineups_at(&self, round: u8) -> Vec<(String, Vec<u8>)> {
+        self.teams
+            .iter()
+            .filter_map(|t| t.get_lineup_at(round).map(|l| (t.name(), l)))
+            .collect()
+    }
+
+    // points for a round in team creation order, skipping teams without any
+    fn scores_at(&self, round: u8) -> Vec<(String, i16)> {
+        self.teams
+            .iter()
+            .filter_map(|t| t.get_points_at(round).map(|p| (t.name(), p)))
+            .collect()
+    }
+}
+
+// one line for each driver whose result was added, removed, or changed
+fn results_changes(
+    before: &HashMap<u8, DriverResult>,
+    after: &HashMap<u8, DriverResult>,
+) -> Vec<String> {
+    fn describe(result: &DriverResult) -> String {
+        format!(
+            "finished {}, started {}, qualified {}",
+            result.final_position, result.grid_position, result.qualifying_position
+        )
+    }
+
+    let mut drivers: Vec<u8> = before.keys().chain(after.keys()).copied().collect();
+    drivers.sort();
+    drivers.dedup();
+    drivers
+        .into_iter()
+        .filter_map(|driver| match (before.get(&driver), after.get(&driver)) {
+            (Some(old), Some(new)) if old == new => None,
+            (Some(old), Some(new)) => Some(format!(
+                "#{driver:02}: {} -> {}",
+                describe(old),
+                describe(new)
+            )),
+            (Some(_), None) => Some(format!("#{driver:02}: removed")),
+            (None, Some(new)) => Some(format!("#{driver:02}: {}", describe(new))),
+            (None, None) => None,
+        })
+        .collect()
+}
+
+// the time of creation in nanoseconds, moved on by one if another season was created at the same time
+pub(super) fn new_season_id() -> u64 {
+    static LAST_ID: AtomicU64 = AtomicU64::new(0);
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or_default();
+    let last = LAST_ID
+        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |last| {
+            Some(now.max(last + 1))
+        })
+        .expect("IMPOSSIBLE: THE UPDATE ALWAYS RETURNS SOME");
+    now.max(last + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use draft::ReplaceAll;
+
+    // a season of teams with one driver each, scored with formula one points
+    fn season(draft_choice: DraftChoice, teams: &[&str]) -> FantasySeason {
+        FantasySeason::new(
+            String::from("Office League"),
+            ScoreChoice::FormulaOne,
+            draft_choice,
+            RollOnChoice::default(),
+            DraftSchedule::default(),
+            teams.iter().map(|t| t.to_string()),
+            1,
+            2024,
+            20,
+            true,
+        )
+    }
+
+    // (driver, final position, grid position), qualifying where they started
+    fn results(drivers: &[(u8, u8, u8)]) -> RaceResults {
+        RaceResults::downloaded(
+            drivers
+                .iter()
+                .map(|(d, f, g)| (*d, DriverResult::new(*f, *g, *g)))
+                .collect(),
+        )
+    }
+
+    fn lineups(lineups: &[(&str, &[u8])]) -> ReplaceAll {
+        ReplaceAll::new(
+            lineups
+                .iter()
+                .map(|(team, drivers)| (team.to_string(), drivers.to_vec()))
+                .collect(),
+        )
+    }
+
+    // drafts and scores a round where alpha's driver 1 wins from 5th and bravo's driver 2 comes
+    // 2nd from pole
+    fn play(season: &mut FantasySeason, round: u8) {
+        season
+            .draft(round, &mut lineups(&[("Alpha", &[1]), ("Bravo", &[2])]))
+            .unwrap();
+        season
+            .update_results(round, results(&[(1, 1, 5), (2, 2, 1)]))
+            .unwrap();
+        season.score(round).unwrap();
+    }
+
+    fn points(season: &FantasySeason, round: u8) -> Vec<(String, i16)> {
+        season.get_points_at(round).unwrap()
+    }
+
+    fn named(points: &[(&str, i16)]) -> Vec<(String, i16)> {
+        points.iter().map(|(t, p)| (t.to_string(), *p)).collect()
+    }
+
+    #[test]
+    fn a_change_in_round_one_applies_to_every_round() {
+        let mut season = season(DraftChoice::ReplaceAll, &["Alpha", "Bravo"]);
+        season
+            .change_score_choice(1, ScoreChoice::RacePosition, false)
+            .unwrap();
+
+        assert_eq!(season.get_score_choice_at(1), ScoreChoice::RacePosition);
+        assert_eq!(season.get_score_choice_at(10), ScoreChoice::RacePosition);
+        assert_eq!(season.get_score_choice(), ScoreChoice::RacePosition);
+    }
+
+    #[test]
+    fn a_forward_change_leaves_earlier_rounds_alone() {
+        let mut season = season(DraftChoice::ReplaceAll, &["Alpha", "Bravo"]);
+        season
+            .change_score_choice(3, ScoreChoice::RacePosition, false)
+            .unwrap();
+
+        assert_eq!(season.get_score_choice_at(2), ScoreChoice::FormulaOne);
+        assert_eq!(season.get_score_choice_at(3), ScoreChoice::RacePosition);
+        assert_eq!(season.get_score_choice_at(4), ScoreChoice::RacePosition);
+    }
+
+    #[test]
+    fn a_retroactive_change_after_a_forward_change_applies_to_every_round() {
+        let mut season = season(DraftChoice::ReplaceAll, &["Alpha", "Bravo"]);
+        season
+            .change_score_choice(3, ScoreChoice::RacePosition, false)
+            .unwrap();
+        season
+            .change_score_choice(5, ScoreChoice::Improvement, true)
+            .unwrap();
+
+        for round in [1, 3, 5, 8] {
+            assert_eq!(season.get_score_choice_at(round), ScoreChoice::Improvement);
+        }
+
+        // a later forward change still only applies from its round
+        season
+            .change_score_choice(6, ScoreChoice::Domination, false)
+            .unwrap();
+        assert_eq!(season.get_score_choice_at(5), ScoreChoice::Improvement);
+        assert_eq!(season.get_score_choice_at(6), ScoreChoice::Domination);
+    }
+
+    #[test]
+    fn changing_the_score_choice_rescores_the_rounds_it_applies_to() {
+        let mut season = season(DraftChoice::ReplaceAll, &["Alpha", "Bravo"]);
+        play(&mut season, 1);
+        play(&mut season, 2);
+        assert_eq!(points(&season, 2), named(&[("Alpha", 25), ("Bravo", 18)]));
+
+        season
+            .change_score_choice(2, ScoreChoice::RacePosition, false)
+            .unwrap();
+        assert_eq!(points(&season, 1), named(&[("Alpha", 25), ("Bravo", 18)]));
+        assert_eq!(points(&season, 2), named(&[("Alpha", 20), ("Bravo", 19)]));
+
+        season
+            .change_score_choice(2, ScoreChoice::Improvement, true)
+            .unwrap();
+        assert_eq!(points(&season, 1), named(&[("Alpha", 24), ("Bravo", 18)]));
+        assert_eq!(points(&season, 2), named(&[("Alpha", 24), ("Bravo", 18)]));
+        assert_eq!(
+            season.get_points_by(2),
+            named(&[("Alpha", 48), ("Bravo", 36)])
+        );
+    }
+
+    // a roll on season where alpha drafted 1, 2 and 3, and then 2, 1 and 5 for round 2, which
+    // 2 finished last, 1 won, and 5 came 3rd, if the results are in
+    fn roll_on(choice: RollOnChoice, with_results: bool) -> FantasySeason {
+        let mut season = FantasySeason::new(
+            String::from("Office League"),
+            ScoreChoice::FormulaOne,
+            DraftChoice::RollOn,
+            choice,
+            DraftSchedule::default(),
+            [String::from("Alpha")],
+            3,
+            2024,
+            20,
+            true,
+        );
+        season
+            .draft(1, &mut lineups(&[("Alpha", &[1, 2, 3])]))
+            .unwrap();
+        season
+            .draft(2, &mut lineups(&[("Alpha", &[2, 1, 5])]))
+            .unwrap();
+        if with_results {
+            season
+                .update_results(2, results(&[(2, 20, 1), (1, 1, 2), (5, 3, 3)]))
+                .unwrap();
+        }
+        season
+    }
+
+    fn releases(season: &FantasySeason, round: u8) -> Vec<u8> {
+        season.get_roll_on_releases(round).unwrap()["Alpha"].clone()
+    }
+
+    #[test]
+    fn roll_on_releases_follow_the_roll_on_choice() {
+        assert_eq!(releases(&roll_on(RollOnChoice::DropLast, true), 3), [5]);
+        assert_eq!(
+            releases(&roll_on(RollOnChoice::DropLowestScoring, true), 3),
+            [2]
+        );
+        assert_eq!(
+            releases(&roll_on(RollOnChoice::DropLongestHeld, true), 3),
+            [1]
+        );
+        assert!(
+            roll_on(RollOnChoice::TeamChooses, true)
+                .get_roll_on_releases(3)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn without_results_the_lowest_scoring_driver_is_the_last() {
+        assert_eq!(
+            releases(&roll_on(RollOnChoice::DropLowestScoring, false), 3),
+            [5]
+        );
+    }
+
+    #[test]
+    fn roll_on_releases_follow_the_lineup_size() {
+        let mut grown = roll_on(RollOnChoice::DropLast, true);
+        grown.set_lineup_size(3, 4).unwrap();
+        assert_eq!(releases(&grown, 3), [5]);
+
+        let mut shrunk = roll_on(RollOnChoice::DropLast, true);
+        shrunk.set_lineup_size(3, 2).unwrap();
+        assert_eq!(releases(&shrunk, 3), [1, 5]);
+
+        let mut shrunk = roll_on(RollOnChoice::DropLongestHeld, true);
+        shrunk.set_lineup_size(3, 2).unwrap();
+        assert_eq!(releases(&shrunk, 3), [2, 1]);
+    }
+
+    #[test]
+    fn catching_up_stops_where_a_round_fails_to_score() {
+        let mut season = season(DraftChoice::Skip, &["Alpha", "Bravo"]);
+        play(&mut season, 1);
+        season
+            .update_results(2, results(&[(1, 2, 2), (2, 1, 1)]))
+            .unwrap();
+        // bravo's driver did not race in round 3
+        season.update_results(3, results(&[(1, 1, 1)])).unwrap();
+        season
+            .update_results(4, results(&[(1, 1, 1), (2, 2, 2)]))
+            .unwrap();
+
+        let report = season.catch_up();
+        assert_eq!(report.drafted, [2, 3]);
+        assert_eq!(report.scored, [2]);
+        assert!(matches!(
+            report.stopped,
+            CatchUpStop::ScoreFailed(3, ScoreError::DriverDidNotRace(2))
+        ));
+
+        // the rounds before the failure stay drafted and scored, and the failed round is left
+        // drafted but unscored, with nothing after it touched
+        assert_eq!(points(&season, 2), named(&[("Bravo", 25), ("Alpha", 18)]));
+        assert_eq!(season.get_lineup_at(3).len(), 2);
+        assert!(season.get_points_at(3).is_none());
+        assert!(season.get_lineup_at(4).is_empty());
+        assert_eq!(season.get_first_unscored_round(), 3);
+        assert_eq!(
+            season.get_points_by(4),
+            named(&[("Alpha", 43), ("Bravo", 43)])
+        );
+    }
+
+    #[test]
+    fn catching_up_stops_at_a_round_that_needs_drafting() {
+        let mut season = season(DraftChoice::ReplaceAll, &["Alpha", "Bravo"]);
+        season.draft_schedule = DraftSchedule::EveryNRounds(2);
+        play(&mut season, 1);
+        for round in 2..=4 {
+            season
+                .update_results(round, results(&[(1, 1, 5), (2, 2, 1)]))
+                .unwrap();
+        }
+
+        let report = season.catch_up();
+        assert_eq!(report.drafted, [2]);
+        assert_eq!(report.scored, [2]);
+        assert!(matches!(report.stopped, CatchUpStop::NeedsDraft(3)));
+        assert_eq!(report.stopped_at(), Some(3));
+
+        assert!(season.get_lineup_at(3).is_empty());
+        assert_eq!(season.get_first_unscored_round(), 3);
+
+        // once the round is drafted, catching up carries on from it
+        season
+            .draft(3, &mut lineups(&[("Alpha", &[2]), ("Bravo", &[1])]))
+            .unwrap();
+        let report = season.catch_up();
+        assert_eq!(report.drafted, [4]);
+        assert_eq!(report.scored, [3, 4]);
+        assert!(matches!(report.stopped, CatchUpStop::NeedsDraft(5)));
+        assert_eq!(points(&season, 4), named(&[("Bravo", 25), ("Alpha", 18)]));
+    }
+
+    #[test]
+    fn pit_lane_starts_gain_no_positions() {
+        let mut season = season(DraftChoice::ReplaceAll, &["Alpha", "Bravo"]);
+        season.update_results(1, results(&[(1, 1, 5)])).unwrap();
+        season.update_results(2, results(&[(1, 8, 0)])).unwrap();
+        season.update_results(3, results(&[(1, 4, 3)])).unwrap();
+
+        let report = season.get_driver_report(1);
+        assert_eq!(report.rounds.len(), 3);
+        assert_eq!(report.positions_gained, 3);
+    }
 }
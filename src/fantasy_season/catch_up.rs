@@ -0,0 +1,84 @@
+use super::error::{DraftError, ScoreError};
+use std::fmt::Display;
+
+// what a catch up did, and why it stopped
+#[derive(Debug, Clone)]
+pub struct CatchUpReport {
+    pub drafted: Vec<u8>,
+    pub scored: Vec<u8>,
+    pub stopped: CatchUpStop,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum CatchUpStop {
+    NeedsDraft(u8),
+    DraftFailed(u8, DraftError),
+    NoResults(u8),
+    ScoreFailed(u8, ScoreError),
+    SeasonComplete,
+}
+
+impl CatchUpReport {
+    pub(super) fn new() -> CatchUpReport {
+        CatchUpReport {
+            drafted: Vec::new(),
+            scored: Vec::new(),
+            stopped: CatchUpStop::SeasonComplete,
+        }
+    }
+
+    // the round that needs attention next
+    pub fn stopped_at(&self) -> Option<u8> {
+        match self.stopped {
+            CatchUpStop::NeedsDraft(round)
+            | CatchUpStop::DraftFailed(round, _)
+            | CatchUpStop::NoResults(round)
+            | CatchUpStop::ScoreFailed(round, _) => Some(round),
+            CatchUpStop::SeasonComplete => None,
+        }
+    }
+
+    // a line by line summary, suitable for showing to the league manager
+    pub fn summary(&self) -> Vec<String> {
+        fn rounds(rounds: &[u8]) -> String {
+            if rounds.is_empty() {
+                String::from("none")
+            } else {
+                rounds
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }
+        }
+
+        vec![
+            format!("rounds drafted: {}", rounds(&self.drafted)),
+            format!("rounds scored: {}", rounds(&self.scored)),
+            self.stopped.to_string(),
+        ]
+    }
+}
+
+impl Display for CatchUpStop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CatchUpStop::NeedsDraft(round) => {
+                write!(
+                    f,
+                    "stopped at round {round}: the lineup needs to be drafted"
+                )
+            }
+            CatchUpStop::DraftFailed(round, err) => {
+                write!(f, "stopped at round {round}: {err}")
+            }
+            CatchUpStop::NoResults(round) => {
+                write!(f, "stopped at round {round}: results are not available")
+            }
+            CatchUpStop::ScoreFailed(round, err) => {
+                write!(f, "stopped at round {round}: {err}")
+            }
+            CatchUpStop::SeasonComplete => write!(f, "every round has been scored"),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use super::error::DraftError;
+use super::error::{DraftError, ImportError};
 use crate::fantasy_season::error::DraftError::PreviousRoundLineupDoesNotExist;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -23,11 +23,128 @@ impl Display for DraftChoice {
     }
 }
 
+// how roll on seasons decide which drivers leave a lineup to make room for the drafted ones
+#[derive(Copy, Clone, Default, Debug, PartialOrd, PartialEq, Ord, Eq, Deserialize, Serialize)]
+pub enum RollOnChoice {
+    #[default]
+    DropLast,
+    TeamChooses,
+    DropLowestScoring,
+    DropLongestHeld,
+}
+
+impl Display for RollOnChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            RollOnChoice::DropLast => String::from("Drop Last"),
+            RollOnChoice::TeamChooses => String::from("Team Chooses"),
+            RollOnChoice::DropLowestScoring => String::from("Drop Lowest Scoring"),
+            RollOnChoice::DropLongestHeld => String::from("Drop Longest Held"),
+        };
+        write!(f, "{str}")
+    }
+}
+
+// which rounds need a new draft in seasons that do not skip drafting. Every other round keeps the
+// previous lineups, as skip seasons do
+#[derive(Clone, Default, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum DraftSchedule {
+    #[default]
+    EveryRound,
+    EveryNRounds(u8),
+    AtRounds(Vec<u8>),
+}
+
+impl DraftSchedule {
+    // the schedule described by a kind of schedule and its input, which is the number of rounds
+    // between drafts, or the list of rounds to draft at separated by commas or spaces
+    pub fn parse(choice: ScheduleChoice, input: &str) -> Option<DraftSchedule> {
+        match choice {
+            ScheduleChoice::EveryRound => Some(DraftSchedule::EveryRound),
+            ScheduleChoice::EveryNRounds => input
+                .trim()
+                .parse::<u8>()
+                .ok()
+                .filter(|n| *n > 0)
+                .map(DraftSchedule::EveryNRounds),
+            ScheduleChoice::AtRounds => {
+                let mut rounds = input
+                    .split([',', ' '])
+                    .filter(|r| !r.is_empty())
+                    .map(|r| r.parse::<u8>().ok().filter(|r| *r > 0))
+                    .collect::<Option<Vec<_>>>()?;
+                rounds.sort();
+                rounds.dedup();
+                (!rounds.is_empty()).then_some(DraftSchedule::AtRounds(rounds))
+            }
+        }
+    }
+
+    pub fn choice(&self) -> ScheduleChoice {
+        match self {
+            DraftSchedule::EveryRound => ScheduleChoice::EveryRound,
+            DraftSchedule::EveryNRounds(_) => ScheduleChoice::EveryNRounds,
+            DraftSchedule::AtRounds(_) => ScheduleChoice::AtRounds,
+        }
+    }
+
+    // the input that parses back into this schedule
+    pub fn input(&self) -> String {
+        match self {
+            DraftSchedule::EveryRound => String::new(),
+            DraftSchedule::EveryNRounds(n) => n.to_string(),
+            DraftSchedule::AtRounds(rounds) => rounds
+                .iter()
+                .map(u8::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+        }
+    }
+
+    pub fn requires_draft(&self, round: u8) -> bool {
+        match self {
+            DraftSchedule::EveryRound => true,
+            DraftSchedule::EveryNRounds(n) => round.saturating_sub(1).is_multiple_of(*n),
+            DraftSchedule::AtRounds(rounds) => rounds.contains(&round),
+        }
+    }
+}
+
+impl Display for DraftSchedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DraftSchedule::EveryRound => write!(f, "Every Round"),
+            DraftSchedule::EveryNRounds(n) => write!(f, "Every {n} Rounds"),
+            DraftSchedule::AtRounds(_) => write!(f, "At Rounds {}", self.input()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+pub enum ScheduleChoice {
+    #[default]
+    EveryRound,
+    EveryNRounds,
+    AtRounds,
+}
+
+impl Display for ScheduleChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            ScheduleChoice::EveryRound => String::from("Every Round"),
+            ScheduleChoice::EveryNRounds => String::from("Every N Rounds"),
+            ScheduleChoice::AtRounds => String::from("At Rounds"),
+        };
+        write!(f, "{str}")
+    }
+}
+
 pub trait Drafter: Debug + Sync + Send {
     fn draft(
         &mut self,
         team_name: &str,
         previous_drivers: Option<&[u8]>,
+        lineup_size: usize,
     ) -> Result<Vec<u8>, DraftError>;
 }
 
@@ -40,24 +157,56 @@ impl Skip {
     }
 }
 
+// keeps the previous lineup, dropping drivers from the end if the lineup has shrunk. A lineup
+// that has grown cannot be skipped, as nobody has picked the extra drivers
 impl Drafter for Skip {
-    fn draft(&mut self, _: &str, previous_drivers: Option<&[u8]>) -> Result<Vec<u8>, DraftError> {
+    fn draft(
+        &mut self,
+        _: &str,
+        previous_drivers: Option<&[u8]>,
+        lineup_size: usize,
+    ) -> Result<Vec<u8>, DraftError> {
         if let Some(prev) = previous_drivers {
-            Ok(prev.to_vec())
+            if prev.len() < lineup_size {
+                return Err(DraftError::IncompleteDrafter);
+            }
+            Ok(prev[..lineup_size].to_vec())
         } else {
             Err(PreviousRoundLineupDoesNotExist)
         }
     }
 }
 
+// puts the drafted drivers at the front of the previous lineup, and removes the released drivers
+// from it. Teams without released drivers drop drivers from the end until the lineup is the right
+// size
 #[derive(Default, Debug, Clone)]
 pub struct RollOn {
-    drafted_drivers: HashMap<String, u8>,
+    drafted_drivers: HashMap<String, Vec<u8>>,
+    released_drivers: HashMap<String, Vec<u8>>,
 }
 
 impl RollOn {
-    pub(crate) fn new(drafted_drivers: HashMap<String, u8>) -> RollOn {
-        RollOn { drafted_drivers }
+    pub(crate) fn new(
+        drafted_drivers: HashMap<String, Vec<u8>>,
+        released_drivers: HashMap<String, Vec<u8>>,
+    ) -> RollOn {
+        RollOn {
+            drafted_drivers,
+            released_drivers,
+        }
+    }
+
+    // how many drivers a team must draft for the lineup to reach its size, which is always at
+    // least one, and more if the lineup has grown
+    pub fn picks_needed(previous_size: usize, lineup_size: usize) -> usize {
+        (lineup_size + 1).saturating_sub(previous_size).max(1)
+    }
+
+    // how many drivers must leave a lineup to make room for the drafted ones
+    pub fn releases_needed(previous_size: usize, lineup_size: usize) -> usize {
+        (previous_size + RollOn::picks_needed(previous_size, lineup_size))
+            .saturating_sub(lineup_size)
     }
 }
 
@@ -66,16 +215,29 @@ impl Drafter for RollOn {
         &mut self,
         team: &str,
         previous_drivers: Option<&[u8]>,
+        lineup_size: usize,
     ) -> Result<Vec<u8>, DraftError> {
         if let Some(prev) = previous_drivers {
-            let team = team.to_string();
-            if self.drafted_drivers.contains_key(&team) {
-                let mut lineup = prev.to_vec();
-                lineup.pop();
-                lineup.insert(0, *self.drafted_drivers.get(&team).unwrap());
-                Ok(lineup)
-            } else {
-                Err(DraftError::IncompleteDrafter)
+            match self.drafted_drivers.get(team) {
+                Some(drafted) if drafted.len() == RollOn::picks_needed(prev.len(), lineup_size) => {
+                    let mut lineup = drafted.clone();
+                    match self.released_drivers.get(team) {
+                        Some(released)
+                            if released.len()
+                                == RollOn::releases_needed(prev.len(), lineup_size)
+                                && released.iter().all(|d| prev.contains(d)) =>
+                        {
+                            lineup.extend(prev.iter().filter(|d| !released.contains(d)));
+                        }
+                        Some(_) => return Err(DraftError::IncompleteDrafter),
+                        None => {
+                            lineup.extend_from_slice(prev);
+                            lineup.truncate(lineup_size);
+                        }
+                    }
+                    Ok(lineup)
+                }
+                _ => Err(DraftError::IncompleteDrafter),
             }
         } else {
             Err(DraftError::PreviousRoundLineupDoesNotExist)
@@ -94,7 +256,7 @@ impl ReplaceAll {
     }
 }
 impl Drafter for ReplaceAll {
-    fn draft(&mut self, team: &str, _: Option<&[u8]>) -> Result<Vec<u8>, DraftError> {
+    fn draft(&mut self, team: &str, _: Option<&[u8]>, _: usize) -> Result<Vec<u8>, DraftError> {
         let team = team.to_string();
         if self.team_lineups.contains_key(&team) {
             Ok(self.team_lineups.remove(&team).unwrap())
@@ -103,3 +265,292 @@ impl Drafter for ReplaceAll {
         }
     }
 }
+
+// parses lineups in the form `team,driver1,driver2,...`, one team per line. Fields may be separated
+// by commas or tabs, and blank lines or lines starting with # are ignored
+pub fn parse_lineups(text: &str) -> Result<Vec<(String, Vec<u8>)>, ImportError> {
+    let mut lineups: Vec<(String, Vec<u8>)> = Vec::new();
+
+    for (idx, line) in text.lines().enumerate() {
+        let line_num = idx + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut fields = line.split([',', '\t']).map(str::trim);
+        let team = fields
+            .next()
+            .filter(|t| !t.is_empty())
+            .ok_or(ImportError::MissingTeamName(line_num))?;
+        if lineups.iter().any(|(t, _)| t == team) {
+            return Err(ImportError::DuplicateTeam(line_num));
+        }
+
+        let drivers = fields
+            .filter(|d| !d.is_empty())
+            .map(|d| {
+                d.parse::<u8>()
+                    .ok()
+                    .filter(|num| *num < 100)
+                    .ok_or(ImportError::InvalidDriverNumber(line_num))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        lineups.push((team.to_string(), drivers));
+    }
+
+    Ok(lineups)
+}
+
+// checks parsed lineups before they are used, so that each is for a known team if the teams are
+// given, has the right number of drivers, and shares no drivers with another lineup when
+// uniqueness is enforced
+pub fn check_lineups(
+    lineups: &[(String, Vec<u8>)],
+    team_names: Option<&[String]>,
+    lineup_size: usize,
+    enforce_uniqueness: bool,
+) -> Result<(), ImportError> {
+    if lineups.is_empty() {
+        return Err(ImportError::NoLineups);
+    }
+
+    let mut seen = Vec::new();
+    for (team, drivers) in lineups {
+        if team_names.is_some_and(|names| !names.contains(team)) {
+            return Err(ImportError::UnknownTeam(team.clone()));
+        }
+        if drivers.len() != lineup_size {
+            return Err(ImportError::WrongDriverCount(team.clone(), lineup_size));
+        }
+        for driver in drivers {
+            if enforce_uniqueness && seen.contains(driver) {
+                return Err(ImportError::NonUniqueDriver(*driver));
+            }
+            seen.push(*driver);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // alpha's lineup after rolling on from the previous one
+    fn roll_on(
+        previous: &[u8],
+        lineup_size: usize,
+        drafted: &[u8],
+        released: Option<&[u8]>,
+    ) -> Result<Vec<u8>, DraftError> {
+        let team = || String::from("Alpha");
+        let released = released
+            .map(|r| HashMap::from([(team(), r.to_vec())]))
+            .unwrap_or_default();
+        RollOn::new(HashMap::from([(team(), drafted.to_vec())]), released).draft(
+            "Alpha",
+            Some(previous),
+            lineup_size,
+        )
+    }
+
+    #[test]
+    fn a_lineup_of_the_same_size_swaps_one_driver() {
+        assert_eq!(RollOn::picks_needed(3, 3), 1);
+        assert_eq!(RollOn::releases_needed(3, 3), 1);
+    }
+
+    #[test]
+    fn a_grown_lineup_picks_the_extra_drivers() {
+        assert_eq!(RollOn::picks_needed(2, 4), 3);
+        assert_eq!(RollOn::releases_needed(2, 4), 1);
+    }
+
+    #[test]
+    fn a_shrunk_lineup_releases_the_extra_drivers() {
+        assert_eq!(RollOn::picks_needed(4, 2), 1);
+        assert_eq!(RollOn::releases_needed(4, 2), 3);
+    }
+
+    #[test]
+    fn rolling_on_puts_the_drafted_drivers_first() {
+        assert_eq!(roll_on(&[1, 2, 3], 3, &[4], Some(&[2])).unwrap(), [4, 1, 3]);
+        assert_eq!(roll_on(&[1, 2, 3], 3, &[4], None).unwrap(), [4, 1, 2]);
+        assert_eq!(
+            roll_on(&[1, 2], 4, &[3, 4, 5], Some(&[2])).unwrap(),
+            [3, 4, 5, 1]
+        );
+        assert_eq!(roll_on(&[1, 2, 3, 4], 2, &[5], None).unwrap(), [5, 1]);
+    }
+
+    #[test]
+    fn rolling_on_with_the_wrong_drivers_is_incomplete() {
+        // too few picks for a grown lineup
+        assert!(matches!(
+            roll_on(&[1, 2], 4, &[3], None),
+            Err(DraftError::IncompleteDrafter)
+        ));
+        // too few releases for a shrunk lineup
+        assert!(matches!(
+            roll_on(&[1, 2, 3, 4], 2, &[5], Some(&[4])),
+            Err(DraftError::IncompleteDrafter)
+        ));
+        // releasing a driver who was not in the lineup
+        assert!(matches!(
+            roll_on(&[1, 2, 3], 3, &[4], Some(&[9])),
+            Err(DraftError::IncompleteDrafter)
+        ));
+    }
+
+    #[test]
+    fn schedules_parse_from_their_input() {
+        assert_eq!(
+            DraftSchedule::parse(ScheduleChoice::EveryRound, "ignored"),
+            Some(DraftSchedule::EveryRound)
+        );
+        assert_eq!(
+            DraftSchedule::parse(ScheduleChoice::EveryNRounds, " 3 "),
+            Some(DraftSchedule::EveryNRounds(3))
+        );
+        assert_eq!(
+            DraftSchedule::parse(ScheduleChoice::AtRounds, "12, 4 4,,1"),
+            Some(DraftSchedule::AtRounds(vec![1, 4, 12]))
+        );
+    }
+
+    #[test]
+    fn invalid_schedules_do_not_parse() {
+        for input in ["", "0", "-2", "two", "256", "3 4"] {
+            assert_eq!(
+                DraftSchedule::parse(ScheduleChoice::EveryNRounds, input),
+                None
+            );
+        }
+        for input in ["", " , ", "0", "1, two", "1, 256"] {
+            assert_eq!(DraftSchedule::parse(ScheduleChoice::AtRounds, input), None);
+        }
+    }
+
+    #[test]
+    fn a_schedule_parses_back_from_its_input() {
+        for schedule in [
+            DraftSchedule::EveryRound,
+            DraftSchedule::EveryNRounds(2),
+            DraftSchedule::AtRounds(vec![1, 5, 9]),
+        ] {
+            assert_eq!(
+                DraftSchedule::parse(schedule.choice(), &schedule.input()),
+                Some(schedule)
+            );
+        }
+    }
+
+    #[test]
+    fn the_first_round_is_drafted_every_n_rounds() {
+        let schedule = DraftSchedule::EveryNRounds(3);
+        assert!(schedule.requires_draft(1));
+        assert!(schedule.requires_draft(4));
+        assert!(schedule.requires_draft(7));
+        for round in [2, 3, 5, 6, 8] {
+            assert!(!schedule.requires_draft(round));
+        }
+        assert!(DraftSchedule::EveryNRounds(1).requires_draft(2));
+    }
+
+    #[test]
+    fn rounds_are_drafted_only_at_the_listed_rounds() {
+        let schedule = DraftSchedule::AtRounds(vec![1, 6]);
+        assert!(schedule.requires_draft(1));
+        assert!(schedule.requires_draft(6));
+        assert!(!schedule.requires_draft(3));
+        assert!(!schedule.requires_draft(12));
+        assert!((1..=24).all(|r| DraftSchedule::EveryRound.requires_draft(r)));
+    }
+
+    fn parsed(lineups: &[(&str, &[u8])]) -> Vec<(String, Vec<u8>)> {
+        lineups
+            .iter()
+            .map(|(team, drivers)| (team.to_string(), drivers.to_vec()))
+            .collect()
+    }
+
+    #[test]
+    fn lineups_parse_from_commas_or_tabs() {
+        let text = "# team, drivers\nAlpha, 1, 44\n\n  Bravo\t16\t 4 \nCharlie,63,,81,\n";
+        assert_eq!(
+            parse_lineups(text).unwrap(),
+            parsed(&[
+                ("Alpha", &[1, 44]),
+                ("Bravo", &[16, 4]),
+                ("Charlie", &[63, 81])
+            ])
+        );
+    }
+
+    #[test]
+    fn malformed_lines_are_errors_with_their_line_number() {
+        assert!(matches!(
+            parse_lineups("Alpha,1\n,2,3"),
+            Err(ImportError::MissingTeamName(2))
+        ));
+        assert!(matches!(
+            parse_lineups("Alpha,1,two"),
+            Err(ImportError::InvalidDriverNumber(1))
+        ));
+        assert!(matches!(
+            parse_lineups("\nAlpha,1,100"),
+            Err(ImportError::InvalidDriverNumber(2))
+        ));
+        assert!(matches!(
+            parse_lineups("Alpha,-1"),
+            Err(ImportError::InvalidDriverNumber(1))
+        ));
+    }
+
+    #[test]
+    fn a_team_listed_twice_is_an_error() {
+        assert!(matches!(
+            parse_lineups("Alpha,1\nBravo,2\nAlpha,3"),
+            Err(ImportError::DuplicateTeam(3))
+        ));
+    }
+
+    #[test]
+    fn lineups_for_unknown_teams_are_rejected() {
+        let teams = [String::from("Alpha"), String::from("Bravo")];
+        let lineups = parsed(&[("Alpha", &[1]), ("Bravco", &[2])]);
+
+        assert!(matches!(
+            check_lineups(&lineups, Some(&teams), 1, true),
+            Err(ImportError::UnknownTeam(team)) if team == "Bravco"
+        ));
+        // without the teams any team is accepted
+        assert!(check_lineups(&lineups, None, 1, true).is_ok());
+    }
+
+    #[test]
+    fn lineups_missing_drivers_are_rejected() {
+        let lineups = parsed(&[("Alpha", &[1, 44]), ("Bravo", &[16])]);
+        assert!(matches!(
+            check_lineups(&lineups, None, 2, true),
+            Err(ImportError::WrongDriverCount(team, 2)) if team == "Bravo"
+        ));
+        assert!(matches!(
+            check_lineups(&[], None, 2, true),
+            Err(ImportError::NoLineups)
+        ));
+    }
+
+    #[test]
+    fn shared_drivers_are_only_rejected_when_uniqueness_is_enforced() {
+        let lineups = parsed(&[("Alpha", &[1, 44]), ("Bravo", &[44, 16])]);
+        assert!(matches!(
+            check_lineups(&lineups, None, 2, true),
+            Err(ImportError::NonUniqueDriver(44))
+        ));
+        assert!(check_lineups(&lineups, None, 2, false).is_ok());
+    }
+}
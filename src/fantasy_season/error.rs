@@ -33,9 +33,12 @@ impl Error for ScoreError {}
 #[derive(Debug, Copy, Clone)]
 pub enum DraftError {
     RoundLineupAlreadyExists(u8),
+    RoundLineupAlreadyScored(u8),
     PreviousRoundLineupDoesNotExist,
     RoundDraftNonUnique(u8, u8),
     IncompleteDrafter,
+    WrongLineupSize(u8, u8),
+    EmptyLineupSize,
 }
 
 impl Display for DraftError {
@@ -44,6 +47,9 @@ impl Display for DraftError {
             DraftError::RoundLineupAlreadyExists(round) => {
                 write!(f, "lineup for round {round} already exist")
             }
+            DraftError::RoundLineupAlreadyScored(round) => {
+                write!(f, "lineup for round {round} has already been scored")
+            }
             DraftError::PreviousRoundLineupDoesNotExist => {
                 write!(f, "lineup for the previous round does not exist",)
             }
@@ -53,6 +59,10 @@ impl Display for DraftError {
             DraftError::IncompleteDrafter => {
                 write!(f, "drafter was constructed with incomplete information")
             }
+            DraftError::WrongLineupSize(round, size) => {
+                write!(f, "lineups for round {round} must have {size} drivers")
+            }
+            DraftError::EmptyLineupSize => write!(f, "lineups must have at least one driver"),
         }
     }
 }
@@ -86,6 +96,7 @@ pub enum ApiError {
     CannotParseJsonRound(u8),
     CannotParseJsonOther,
     RaceResultsNotYetAvailable(u8),
+    WebhookRejected(u16),
 }
 
 impl Display for ApiError {
@@ -100,6 +111,12 @@ impl Display for ApiError {
             ApiError::RaceResultsNotYetAvailable(round) => {
                 write!(f, "results for round {round} are not yet available")
             }
+            ApiError::WebhookRejected(status) => {
+                write!(
+                    f,
+                    "a webhook rejected the notification with status {status}"
+                )
+            }
         }
     }
 }
@@ -143,3 +160,181 @@ impl Display for DeleteError {
 }
 
 impl Error for DeleteError {}
+
+#[derive(Debug, Clone)]
+pub enum ImportError {
+    MissingTeamName(usize),
+    InvalidDriverNumber(usize),
+    DuplicateTeam(usize),
+    NoLineups,
+    EmptyLineup(String),
+    UnknownTeam(String),
+    WrongDriverCount(String, usize),
+    NonUniqueDriver(u8),
+}
+
+impl Display for ImportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImportError::MissingTeamName(line) => write!(f, "line {line} has no team name"),
+            ImportError::InvalidDriverNumber(line) => {
+                write!(f, "line {line} has an invalid driver number")
+            }
+            ImportError::DuplicateTeam(line) => {
+                write!(f, "line {line} lists a team that was already listed")
+            }
+            ImportError::NoLineups => write!(f, "no lineups were found"),
+            ImportError::EmptyLineup(team) => write!(f, "{team} must have at least one driver"),
+            ImportError::UnknownTeam(team) => write!(f, "no team is named {team}"),
+            ImportError::WrongDriverCount(team, size) => {
+                write!(f, "{team} must have exactly {size} drivers")
+            }
+            ImportError::NonUniqueDriver(driver) => {
+                write!(f, "driver #{driver} is in more than one lineup")
+            }
+        }
+    }
+}
+
+impl Error for ImportError {}
+
+#[derive(Debug, Clone)]
+pub enum SettingsError {
+    EmptySeasonName,
+    EmptyTeamName,
+    DuplicateTeamName(String),
+    WrongTeamCount,
+    ZeroGridSize,
+    RoundLineupNonUnique(u8, u8),
+    RescoreFailed(ScoreError),
+    LineupSize(DraftError),
+}
+
+impl Display for SettingsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettingsError::EmptySeasonName => write!(f, "the season must have a name"),
+            SettingsError::EmptyTeamName => write!(f, "every team must have a name"),
+            SettingsError::DuplicateTeamName(name) => {
+                write!(f, "more than one team is named {name}")
+            }
+            SettingsError::WrongTeamCount => {
+                write!(f, "settings were given for the wrong number of teams")
+            }
+            SettingsError::ZeroGridSize => write!(f, "the grid must have at least one driver"),
+            SettingsError::RoundLineupNonUnique(round, driver) => write!(
+                f,
+                "uniqueness cannot be enforced, as round {round} has multiple drivers #{driver}"
+            ),
+            SettingsError::RescoreFailed(se) => {
+                write!(f, "the season could not be rescored: {se}")
+            }
+            SettingsError::LineupSize(de) => {
+                write!(f, "the lineup size cannot be changed: {de}")
+            }
+        }
+    }
+}
+
+impl Error for SettingsError {}
+
+#[derive(Debug, Clone)]
+pub enum TeamError {
+    EmptyTeamName,
+    DuplicateTeamName(String),
+    WrongLineupSize(u8),
+    LineupNonUnique(u8, u8),
+    RoundAlreadyScored(u8),
+    LaterRoundDrafted(u8),
+    TeamDoesNotExist(String),
+    TeamAlreadyRetired(String),
+    RetireBeforeJoining(String),
+}
+
+impl Display for TeamError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TeamError::EmptyTeamName => write!(f, "every team must have a name"),
+            TeamError::DuplicateTeamName(name) => write!(f, "a team is already named {name}"),
+            TeamError::WrongLineupSize(size) => {
+                write!(f, "a lineup must have exactly {size} drivers")
+            }
+            TeamError::LineupNonUnique(round, driver) => {
+                write!(
+                    f,
+                    "driver #{driver} is already in a lineup for round {round}"
+                )
+            }
+            TeamError::RoundAlreadyScored(round) => {
+                write!(f, "round {round} has already been scored")
+            }
+            TeamError::LaterRoundDrafted(round) => {
+                write!(f, "round {round} has already been drafted")
+            }
+            TeamError::TeamDoesNotExist(name) => write!(f, "no team is named {name}"),
+            TeamError::TeamAlreadyRetired(name) => write!(f, "{name} has already retired"),
+            TeamError::RetireBeforeJoining(name) => {
+                write!(f, "{name} cannot retire before the round after they joined")
+            }
+        }
+    }
+}
+
+impl Error for TeamError {}
+
+#[derive(Debug, Clone)]
+pub enum WebhookError {
+    InvalidUrl(String),
+    DuplicateUrl(String),
+}
+
+impl Display for WebhookError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebhookError::InvalidUrl(url) => {
+                write!(f, "{url} is not a url starting with http:// or https://")
+            }
+            WebhookError::DuplicateUrl(url) => write!(f, "{url} is already a webhook"),
+        }
+    }
+}
+
+impl Error for WebhookError {}
+
+#[derive(Debug, Copy, Clone)]
+pub enum ResultsError {
+    WrongDriverCount(usize, u8),
+    DuplicateDriver(u8),
+    InvalidPosition(u8, &'static str, u8),
+    DuplicatePosition(&'static str, u8),
+    RescoreFailed(ScoreError),
+}
+
+impl Display for ResultsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResultsError::WrongDriverCount(count, grid_size) => write!(
+                f,
+                "results must be given for all {grid_size} drivers on the grid, not {count}"
+            ),
+            ResultsError::DuplicateDriver(driver) => {
+                write!(f, "driver #{driver} has more than one result")
+            }
+            ResultsError::InvalidPosition(driver, kind, position) => write!(
+                f,
+                "driver #{driver} cannot have a {kind} position of {position}"
+            ),
+            ResultsError::DuplicatePosition(kind, position) => {
+                write!(
+                    f,
+                    "more than one driver has a {kind} position of {position}"
+                )
+            }
+            ResultsError::RescoreFailed(se) => {
+                write!(f, "the round could not be rescored: {se}")
+            }
+        }
+    }
+}
+
+impl Error for ResultsError {}
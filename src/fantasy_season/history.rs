@@ -0,0 +1,317 @@
+use super::score::ScoreChoice;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+use time::OffsetDateTime;
+
+// a single append-only record of something that changed a season
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HistoryEntry {
+    timestamp: i64,
+    round: u8,
+    event: HistoryEvent,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum HistoryEvent {
+    Drafted(Vec<(String, Vec<u8>)>),
+    LineupEdited {
+        before: Vec<(String, Vec<u8>)>,
+        after: Vec<(String, Vec<u8>)>,
+    },
+    LineupDeleted(Vec<(String, Vec<u8>)>),
+    ResultsAdded,
+    ResultsEdited(Vec<String>),
+    Scored(Vec<(String, i16)>),
+    RoundDeleted(Vec<(String, i16)>),
+    ScoreChoiceChanged {
+        from: ScoreChoice,
+        to: ScoreChoice,
+        retroactive: bool,
+        totals: Vec<(String, i16)>,
+    },
+    SettingsChanged(Vec<String>),
+    TeamAdded {
+        team: String,
+        lineup: Vec<u8>,
+        handicap: i16,
+    },
+    TeamRetired(String),
+    LineupSizeChanged {
+        from: u8,
+        to: u8,
+    },
+}
+
+impl HistoryEntry {
+    pub(super) fn new(round: u8, event: HistoryEvent) -> HistoryEntry {
+        HistoryEntry {
+            timestamp: OffsetDateTime::now_utc().unix_timestamp(),
+            round,
+            event,
+        }
+    }
+
+    pub fn round(&self) -> u8 {
+        self.round
+    }
+
+    pub fn event(&self) -> &HistoryEvent {
+        &self.event
+    }
+
+    // replaces every team name found in `renames` in a single pass, so swapping two names works
+    fn rename_teams(&mut self, renames: &HashMap<String, String>) {
+        let rename = |team: &mut String| {
+            if let Some(new) = renames.get(team) {
+                *team = new.clone();
+            }
+        };
+
+        match &mut self.event {
+            HistoryEvent::Drafted(lineups) | HistoryEvent::LineupDeleted(lineups) => {
+                lineups.iter_mut().for_each(|(team, _)| rename(team))
+            }
+            HistoryEvent::LineupEdited { before, after } => before
+                .iter_mut()
+                .chain(after.iter_mut())
+                .for_each(|(team, _)| rename(team)),
+            HistoryEvent::Scored(points)
+            | HistoryEvent::RoundDeleted(points)
+            | HistoryEvent::ScoreChoiceChanged { totals: points, .. } => {
+                points.iter_mut().for_each(|(team, _)| rename(team))
+            }
+            HistoryEvent::TeamAdded { team, .. } | HistoryEvent::TeamRetired(team) => rename(team),
+            HistoryEvent::ResultsAdded
+            | HistoryEvent::ResultsEdited(_)
+            | HistoryEvent::SettingsChanged(_)
+            | HistoryEvent::LineupSizeChanged { .. } => {}
+        }
+    }
+
+    // formats the time of the entry as YYYY-MM-DD HH:MM in UTC
+    pub fn time(&self) -> String {
+        match OffsetDateTime::from_unix_timestamp(self.timestamp) {
+            Ok(t) => format!(
+                "{}-{:02}-{:02} {:02}:{:02}",
+                t.year(),
+                t.month() as u8,
+                t.day(),
+                t.hour(),
+                t.minute()
+            ),
+            Err(_) => String::from("unknown time"),
+        }
+    }
+}
+
+const TEAM_RENAMED: &str = "team renamed from ";
+
+// how a team being renamed is described in the settings changes, which is also how the history
+// finds out which names a team used to have
+pub(super) fn team_renamed(old: &str, new: &str) -> String {
+    format!("{TEAM_RENAMED}{old} to {new}")
+}
+
+// copies of the entries with every team under its current name. The entries themselves are never
+// changed, so they keep the names the teams had when they were written
+pub(super) fn with_current_names(
+    entries: &[HistoryEntry],
+    team_names: &[String],
+) -> Vec<HistoryEntry> {
+    // from each name a team had at some point to the name it has now
+    let mut current: HashMap<String, String> =
+        team_names.iter().map(|n| (n.clone(), n.clone())).collect();
+
+    let mut renamed: Vec<HistoryEntry> = entries
+        .iter()
+        .rev()
+        .map(|entry| {
+            let mut copy = entry.clone();
+            copy.rename_teams(&current);
+
+            // entries before a rename refer to the team by the name it had before it
+            if let HistoryEvent::SettingsChanged(changes) = &entry.event {
+                // (name before, name after) for every team renamed in this change
+                let renames: Vec<(String, String)> = changes
+                    .iter()
+                    .filter_map(|change| change.strip_prefix(TEAM_RENAMED))
+                    .filter_map(|change| {
+                        // names may contain " to " themselves, but the new name is one we know
+                        change.match_indices(" to ").find_map(|(i, sep)| {
+                            let new = &change[i + sep.len()..];
+                            current
+                                .contains_key(new)
+                                .then(|| (change[..i].to_string(), new.to_string()))
+                        })
+                    })
+                    .collect();
+                // all at once, so swapping two names works
+                let now: Vec<String> = renames
+                    .iter()
+                    .map(|(_, new)| current[new].clone())
+                    .collect();
+                renames.iter().for_each(|(_, new)| {
+                    current.remove(new);
+                });
+                current.extend(renames.into_iter().map(|(old, _)| old).zip(now));
+            }
+            copy
+        })
+        .collect();
+    renamed.reverse();
+    renamed
+}
+
+impl HistoryEvent {
+    // one line per team describing what the event did to it
+    pub fn details(&self) -> Vec<String> {
+        fn lineup(drivers: &[u8]) -> String {
+            drivers.iter().fold(String::new(), |mut x, y| {
+                x.push_str(&format!(" {y:0>2}"));
+                x
+            })
+        }
+
+        match self {
+            HistoryEvent::Drafted(lineups) | HistoryEvent::LineupDeleted(lineups) => lineups
+                .iter()
+                .map(|(team, drivers)| format!("{team}:{}", lineup(drivers)))
+                .collect(),
+            HistoryEvent::LineupEdited { before, after } => after
+                .iter()
+                .filter_map(|(team, drivers)| {
+                    let old = before
+                        .iter()
+                        .find(|(t, _)| t == team)
+                        .map(|(_, d)| d.as_slice())
+                        .unwrap_or_default();
+                    (old != drivers.as_slice())
+                        .then(|| format!("{team}:{} ->{}", lineup(old), lineup(drivers)))
+                })
+                .collect(),
+            HistoryEvent::ResultsAdded => Vec::new(),
+            HistoryEvent::ResultsEdited(changes) => changes.clone(),
+            HistoryEvent::Scored(points) | HistoryEvent::RoundDeleted(points) => points
+                .iter()
+                .map(|(team, points)| format!("{team}: {points}"))
+                .collect(),
+            HistoryEvent::ScoreChoiceChanged {
+                from,
+                to,
+                retroactive,
+                totals,
+            } => {
+                let scope = if *retroactive {
+                    "every round"
+                } else {
+                    "this round onward"
+                };
+                std::iter::once(format!("{from} -> {to} for {scope}"))
+                    .chain(
+                        totals
+                            .iter()
+                            .map(|(team, points)| format!("{team}: {points} total")),
+                    )
+                    .collect()
+            }
+            HistoryEvent::SettingsChanged(changes) => changes.clone(),
+            HistoryEvent::TeamAdded {
+                team,
+                lineup: drivers,
+                handicap,
+            } => vec![format!(
+                "{team}:{}, starting with {handicap} points",
+                lineup(drivers)
+            )],
+            HistoryEvent::TeamRetired(team) => vec![team.clone()],
+            HistoryEvent::LineupSizeChanged { from, to } => {
+                vec![format!("{from} drivers -> {to} drivers")]
+            }
+        }
+    }
+}
+
+impl Display for HistoryEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            HistoryEvent::Drafted(_) => "lineup drafted",
+            HistoryEvent::LineupEdited { .. } => "lineup edited",
+            HistoryEvent::LineupDeleted(_) => "lineup deleted",
+            HistoryEvent::ResultsAdded => "results added",
+            HistoryEvent::ResultsEdited(_) => "results entered by hand",
+            HistoryEvent::Scored(_) => "round scored",
+            HistoryEvent::RoundDeleted(_) => "round deleted",
+            HistoryEvent::ScoreChoiceChanged { .. } => "score mode changed",
+            HistoryEvent::SettingsChanged(_) => "settings changed",
+            HistoryEvent::TeamAdded { .. } => "team added",
+            HistoryEvent::TeamRetired(_) => "team retired",
+            HistoryEvent::LineupSizeChanged { .. } => "lineup size changed",
+        };
+        write!(f, "{str}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(entry: &HistoryEntry) -> Vec<String> {
+        entry.event().details()
+    }
+
+    #[test]
+    fn entries_show_teams_under_their_current_names() {
+        let entries = vec![
+            HistoryEntry::new(
+                1,
+                HistoryEvent::Drafted(vec![
+                    (String::from("Alpha"), vec![1]),
+                    (String::from("Bravo"), vec![2]),
+                ]),
+            ),
+            HistoryEntry::new(
+                1,
+                HistoryEvent::SettingsChanged(vec![team_renamed("Alpha", "Charlie")]),
+            ),
+            HistoryEntry::new(
+                1,
+                HistoryEvent::Scored(vec![
+                    (String::from("Charlie"), 5),
+                    (String::from("Bravo"), 3),
+                ]),
+            ),
+            // the two teams swap names
+            HistoryEntry::new(
+                2,
+                HistoryEvent::SettingsChanged(vec![
+                    team_renamed("Charlie", "Bravo"),
+                    team_renamed("Bravo", "Charlie"),
+                ]),
+            ),
+        ];
+        let current = [String::from("Bravo"), String::from("Charlie")];
+
+        let renamed = with_current_names(&entries, &current);
+        assert_eq!(names(&renamed[0]), ["Bravo: 01", "Charlie: 02"]);
+        assert_eq!(names(&renamed[2]), ["Bravo: 5", "Charlie: 3"]);
+        // the renames themselves, and the entries as written, are left as they were
+        assert_eq!(names(&renamed[1]), names(&entries[1]));
+        assert_eq!(names(&entries[0]), ["Alpha: 01", "Bravo: 02"]);
+        assert_eq!(names(&entries[2]), ["Charlie: 5", "Bravo: 3"]);
+    }
+
+    #[test]
+    fn names_containing_to_are_followed() {
+        let entries = vec![
+            HistoryEntry::new(1, HistoryEvent::TeamRetired(String::from("Back to Back"))),
+            HistoryEntry::new(
+                1,
+                HistoryEvent::SettingsChanged(vec![team_renamed("Back to Back", "Go to Town")]),
+            ),
+        ];
+
+        let renamed = with_current_names(&entries, &[String::from("Go to Town")]);
+        assert_eq!(names(&renamed[0]), ["Go to Town"]);
+    }
+}
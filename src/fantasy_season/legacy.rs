@@ -0,0 +1,71 @@
+use super::draft::{DraftChoice, DraftSchedule, RollOnChoice};
+use super::race_results::{DriverResult, RaceResults};
+use super::score::ScoreChoice;
+use super::status::TeamStatus;
+use super::team::{Team, TeamRound};
+use super::{FantasySeason, new_season_id};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+
+// the layout of seasons as saved in the seasons_v1 file, before the layout was versioned. These
+// types are copies of the ones that were saved, so changing the current types never changes how
+// a v1 save is read
+#[derive(Debug, Deserialize)]
+pub struct FantasySeasonV1 {
+    name: String,
+    teams: Vec<TeamV1>,
+    results: HashMap<u8, RaceResultsV1>,
+    team_status: TeamStatus,
+    score_choice: ScoreChoice,
+    draft_choice: DraftChoice,
+    lineup_size: u8,
+    season: u16,
+    grid_size: u8,
+    enforce_uniqueness: bool,
+}
+
+// the layout of a team as saved in the seasons_v1 file
+#[derive(Debug, Deserialize)]
+pub struct TeamV1 {
+    name: String,
+    rounds: HashMap<u8, TeamRound>,
+}
+
+// the layout of a race's results as saved in the seasons_v1 file
+#[derive(Debug, Deserialize)]
+pub struct RaceResultsV1 {
+    drivers: HashMap<u8, DriverResult>,
+}
+
+impl From<FantasySeasonV1> for FantasySeason {
+    fn from(v1: FantasySeasonV1) -> FantasySeason {
+        FantasySeason {
+            id: new_season_id(),
+            name: v1.name,
+            teams: v1
+                .teams
+                .into_iter()
+                .map(|t| Team::from_rounds(t.name, t.rounds))
+                .collect(),
+            results: v1
+                .results
+                .into_iter()
+                .map(|(round, r)| (round, RaceResults::downloaded(r.drivers)))
+                .collect(),
+            team_status: v1.team_status,
+            score_choice: v1.score_choice,
+            draft_choice: v1.draft_choice,
+            lineup_size: v1.lineup_size,
+            season: v1.season,
+            grid_size: v1.grid_size,
+            enforce_uniqueness: v1.enforce_uniqueness,
+            history: Vec::new(),
+            round_count: None,
+            score_changes: Vec::new(),
+            lineup_sizes: BTreeMap::new(),
+            roll_on_choice: RollOnChoice::default(),
+            draft_schedule: DraftSchedule::default(),
+            webhooks: Vec::new(),
+        }
+    }
+}
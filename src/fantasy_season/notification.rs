@@ -0,0 +1,116 @@
+use super::FantasySeason;
+use super::error::WebhookError;
+use super::report::RoundReport;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+// a url that is sent a POST request when something happens in a season
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Webhook {
+    url: String,
+    format: WebhookFormat,
+}
+
+// the shape of the JSON body, as each chat service expects a different one
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WebhookFormat {
+    #[default]
+    Generic,
+    Discord,
+    Slack,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Notification {
+    RoundScored(u8),
+    ResultsAvailable(u8),
+    Test,
+}
+
+// everything a webhook can be told about a notification. Each webhook format picks what it sends
+#[derive(Debug, Clone)]
+pub struct NotificationMessage {
+    pub notification: Notification,
+    pub season: String,
+    pub year: u16,
+    pub text: String,
+    pub report: Option<RoundReport>,
+}
+
+impl Webhook {
+    pub fn new(url: String, format: WebhookFormat) -> Result<Webhook, WebhookError> {
+        let url = url.trim().to_string();
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            return Err(WebhookError::InvalidUrl(url));
+        }
+        Ok(Webhook { url, format })
+    }
+
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn get_format(&self) -> WebhookFormat {
+        self.format
+    }
+}
+
+impl Notification {
+    pub fn round(&self) -> Option<u8> {
+        match self {
+            Notification::RoundScored(round) | Notification::ResultsAvailable(round) => {
+                Some(*round)
+            }
+            Notification::Test => None,
+        }
+    }
+
+    // a chat message for the notification. Scored rounds include the standings after the round
+    pub(super) fn message(&self, season: &FantasySeason) -> String {
+        let name = format!("{} {}", season.get_name(), season.get_season());
+        match *self {
+            Notification::RoundScored(round) => {
+                let report = season.get_round_report(round);
+                let width = report
+                    .teams
+                    .iter()
+                    .map(|t| t.name.chars().count())
+                    .max()
+                    .unwrap_or_default();
+                let standings: Vec<_> = report
+                    .teams
+                    .iter()
+                    .enumerate()
+                    .map(|(pos, t)| {
+                        format!(
+                            "{:>2}. {:width$} {:>5} ({:+})",
+                            pos + 1,
+                            t.name,
+                            t.total_points,
+                            t.round_points.unwrap_or_default()
+                        )
+                    })
+                    .collect();
+                format!(
+                    "{name}: round {round} has been scored\n```\n{}\n```",
+                    standings.join("\n")
+                )
+            }
+            Notification::ResultsAvailable(round) => {
+                format!("{name}: results for round {round} are available")
+            }
+            Notification::Test => format!("{name}: notifications are working"),
+        }
+    }
+}
+
+impl Display for WebhookFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            WebhookFormat::Generic => String::from("Generic JSON"),
+            WebhookFormat::Discord => String::from("Discord"),
+            WebhookFormat::Slack => String::from("Slack"),
+        };
+        write!(f, "{str}")
+    }
+}
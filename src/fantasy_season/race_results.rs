@@ -1,12 +1,14 @@
-use super::error::DownloadError;
+use super::error::{DownloadError, ResultsError};
 use crate::api::Api;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 // the results of a race for all drivers
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RaceResults {
     pub(super) drivers: HashMap<u8, DriverResult>,
+    // entered by hand rather than downloaded
+    manual: bool,
 }
 
 impl RaceResults {
@@ -38,20 +40,100 @@ impl RaceResults {
             );
         }
 
-        Ok(RaceResults { drivers })
+        Ok(RaceResults::downloaded(drivers))
+    }
+
+    pub(super) fn downloaded(drivers: HashMap<u8, DriverResult>) -> RaceResults {
+        RaceResults {
+            drivers,
+            manual: false,
+        }
+    }
+
+    // results entered by hand. Every place on the grid must be filled, so each finishing and
+    // qualifying position is used exactly once. Grid positions are unique too, apart from 0 for
+    // drivers starting from the pit lane
+    pub(super) fn manual(
+        results: Vec<(u8, DriverResult)>,
+        grid_size: u8,
+    ) -> Result<RaceResults, ResultsError> {
+        if results.len() != grid_size as usize {
+            return Err(ResultsError::WrongDriverCount(results.len(), grid_size));
+        }
+
+        let mut finishes = HashSet::new();
+        let mut grids = HashSet::new();
+        let mut qualifyings = HashSet::new();
+        let mut drivers = HashMap::new();
+        for (driver, result) in results {
+            let positions = [
+                ("finishing", result.final_position, 1, &mut finishes),
+                ("grid", result.grid_position, 0, &mut grids),
+                (
+                    "qualifying",
+                    result.qualifying_position,
+                    1,
+                    &mut qualifyings,
+                ),
+            ];
+            for (kind, position, first, seen) in positions {
+                if !(first..=grid_size).contains(&position) {
+                    return Err(ResultsError::InvalidPosition(driver, kind, position));
+                }
+                if position != 0 && !seen.insert(position) {
+                    return Err(ResultsError::DuplicatePosition(kind, position));
+                }
+            }
+            if drivers.insert(driver, result).is_some() {
+                return Err(ResultsError::DuplicateDriver(driver));
+            }
+        }
+
+        Ok(RaceResults {
+            drivers,
+            manual: true,
+        })
+    }
+
+    pub fn is_manual(&self) -> bool {
+        self.manual
+    }
+
+    // every driver's result, in finishing order
+    pub fn get_drivers(&self) -> Vec<(u8, DriverResult)> {
+        let mut drivers: Vec<_> = self.drivers.iter().map(|(d, r)| (*d, *r)).collect();
+        drivers.sort_by_key(|(_, r)| r.final_position);
+        drivers
+    }
+
+    // downloads each round in order, stopping after the first round that cannot be downloaded
+    pub async fn build_until_unavailable(
+        rounds: Vec<u8>,
+        season: u16,
+    ) -> Vec<(u8, Result<RaceResults, DownloadError>)> {
+        let mut results = Vec::new();
+        for round in rounds {
+            let result = RaceResults::build(round, season).await;
+            let unavailable = result.is_err();
+            results.push((round, result));
+            if unavailable {
+                break;
+            }
+        }
+        results
     }
 }
 
 // the results for a driver in a round
-#[derive(Debug, Copy, Clone, Deserialize, Serialize)]
-pub(super) struct DriverResult {
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DriverResult {
     pub final_position: u8,
     pub grid_position: u8,
     pub qualifying_position: u8,
 }
 
 impl DriverResult {
-    fn new(final_pos: u8, grid_pos: u8, qualifying_pos: u8) -> DriverResult {
+    pub fn new(final_pos: u8, grid_pos: u8, qualifying_pos: u8) -> DriverResult {
         DriverResult {
             final_position: final_pos,
             grid_position: grid_pos,
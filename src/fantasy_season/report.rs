@@ -0,0 +1,78 @@
+use serde::Serialize;
+
+// a round by round breakdown of a single team
+#[derive(Debug, Clone)]
+pub struct TeamReport {
+    pub name: String,
+    pub rounds: Vec<TeamRoundReport>,
+    pub best_round: Option<(u8, i16)>,
+    pub worst_round: Option<(u8, i16)>,
+    pub lineup_changes: Vec<LineupChange>,
+}
+
+// a team's lineup for a round, and how it scored. Rank and points are only set once scored
+#[derive(Debug, Clone)]
+pub struct TeamRoundReport {
+    pub round: u8,
+    pub driver_points: Vec<(u8, Option<i16>)>,
+    pub points: Option<i16>,
+    pub total: i16,
+    pub rank: Option<usize>,
+}
+
+// the drivers that joined and left a team's lineup at the start of a round
+#[derive(Debug, Clone)]
+pub struct LineupChange {
+    pub round: u8,
+    pub added: Vec<u8>,
+    pub removed: Vec<u8>,
+}
+
+// a round by round breakdown of a single driver
+#[derive(Debug, Clone)]
+pub struct DriverReport {
+    pub driver: u8,
+    pub rounds: Vec<DriverRoundReport>,
+    pub average_finish: Option<f32>,
+    pub positions_gained: i16,
+    pub points_per_round_owned: Option<f32>,
+}
+
+// how a driver did in a round, and the teams that had them in their lineup
+#[derive(Debug, Clone)]
+pub struct DriverRoundReport {
+    pub round: u8,
+    pub final_position: u8,
+    pub grid_position: u8,
+    pub qualifying_position: u8,
+    pub points: i16,
+    pub owners: Vec<String>,
+}
+
+// everything shown on the main view for a round, in the order it is shown. Teams are in standings
+// order, and drivers are ordered by points
+#[derive(Debug, Clone, Serialize)]
+pub struct RoundReport {
+    pub season: String,
+    pub year: u16,
+    pub round: u8,
+    pub teams: Vec<TeamStanding>,
+    pub drivers: Vec<DriverStanding>,
+}
+
+// a team's points for and up to a round, and its lineup if the round has been drafted
+#[derive(Debug, Clone, Serialize)]
+pub struct TeamStanding {
+    pub name: String,
+    pub round_points: Option<i16>,
+    pub total_points: i16,
+    pub lineup: Option<Vec<u8>>,
+}
+
+// a driver's points for and up to a round
+#[derive(Debug, Clone, Serialize)]
+pub struct DriverStanding {
+    pub driver: u8,
+    pub round_points: Option<i16>,
+    pub total_points: i16,
+}
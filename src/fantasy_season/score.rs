@@ -61,3 +61,71 @@ impl Scorer for ScoreChoice {
         }
     }
 }
+
+// a switch to a different score mode, taking effect from a round onward, or from the start of the
+// season if it is retroactive
+#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
+pub struct ScoreChange {
+    pub round: u8,
+    pub choice: ScoreChoice,
+    pub retroactive: bool,
+}
+
+// a custom points table, where the driver finishing in position n scores the nth value in the
+// table, and drivers finishing outside the table score nothing
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScoreTable(Vec<i16>);
+
+impl ScoreTable {
+    // parses a table from a list of points separated by commas or spaces, eg: "25, 18, 15"
+    pub fn parse(text: &str) -> Option<ScoreTable> {
+        let points = text
+            .split([',', ' '])
+            .filter(|p| !p.is_empty())
+            .map(|p| p.parse::<i16>().ok())
+            .collect::<Option<Vec<_>>>()?;
+        (!points.is_empty()).then_some(ScoreTable(points))
+    }
+}
+
+impl Display for ScoreTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let points: Vec<_> = self.0.iter().map(ToString::to_string).collect();
+        write!(f, "{}", points.join(", "))
+    }
+}
+
+impl Scorer for ScoreTable {
+    fn score(&self, _: u8, dr: &DriverResult) -> i16 {
+        (dr.final_position as usize)
+            .checked_sub(1)
+            .and_then(|idx| self.0.get(idx))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+// a way of scoring a season other than the one it uses, for comparing rules
+#[derive(Clone, Debug)]
+pub enum AlternateScore {
+    Choice(ScoreChoice),
+    Table(ScoreTable),
+}
+
+impl Display for AlternateScore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlternateScore::Choice(choice) => write!(f, "{choice}"),
+            AlternateScore::Table(table) => write!(f, "custom table ({table})"),
+        }
+    }
+}
+
+impl Scorer for AlternateScore {
+    fn score(&self, grid_size: u8, dr: &DriverResult) -> i16 {
+        match self {
+            AlternateScore::Choice(choice) => choice.score(grid_size, dr),
+            AlternateScore::Table(table) => table.score(grid_size, dr),
+        }
+    }
+}
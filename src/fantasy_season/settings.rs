@@ -0,0 +1,69 @@
+use super::draft::{DraftChoice, DraftSchedule, RollOnChoice};
+use super::history;
+
+// the settings of a season that can still be changed after it has been created. Team names are
+// in the same order as the season's teams, and the lineup size applies from the round the
+// settings are changed in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeasonSettings {
+    pub name: String,
+    pub team_names: Vec<String>,
+    pub lineup_size: u8,
+    pub grid_size: u8,
+    pub draft_choice: DraftChoice,
+    pub roll_on_choice: RollOnChoice,
+    pub draft_schedule: DraftSchedule,
+    pub enforce_uniqueness: bool,
+}
+
+impl SeasonSettings {
+    // a line for every setting that differs between the two, other than the lineup size
+    pub(super) fn changes(&self, new: &SeasonSettings) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        if self.name != new.name {
+            changes.push(format!("season renamed from {} to {}", self.name, new.name));
+        }
+        for (old, new) in self.team_names.iter().zip(&new.team_names) {
+            if old != new {
+                changes.push(history::team_renamed(old, new));
+            }
+        }
+        if self.grid_size != new.grid_size {
+            changes.push(format!(
+                "grid size changed from {} to {}",
+                self.grid_size, new.grid_size
+            ));
+        }
+        if self.draft_choice != new.draft_choice {
+            changes.push(format!(
+                "draft mode changed from {} to {}",
+                self.draft_choice, new.draft_choice
+            ));
+        }
+        if self.roll_on_choice != new.roll_on_choice {
+            changes.push(format!(
+                "roll on mode changed from {} to {}",
+                self.roll_on_choice, new.roll_on_choice
+            ));
+        }
+        if self.draft_schedule != new.draft_schedule {
+            changes.push(format!(
+                "draft schedule changed from {} to {}",
+                self.draft_schedule, new.draft_schedule
+            ));
+        }
+        if self.enforce_uniqueness != new.enforce_uniqueness {
+            changes.push(format!(
+                "uniqueness {}",
+                if new.enforce_uniqueness {
+                    "enforced"
+                } else {
+                    "no longer enforced"
+                }
+            ));
+        }
+
+        changes
+    }
+}
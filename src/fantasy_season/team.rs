@@ -11,12 +11,60 @@ use std::collections::HashMap;
 pub(super) struct Team {
     name: String,
     rounds: HashMap<u8, TeamRound>,
+    joined: u8,
+    retired: Option<u8>,
+    handicap: i16,
 }
 
 impl Team {
     pub fn new(name: String) -> Team {
-        let rounds = HashMap::new();
-        Team { name, rounds }
+        Team::from_rounds(name, HashMap::new())
+    }
+
+    pub fn from_rounds(name: String, rounds: HashMap<u8, TeamRound>) -> Team {
+        Team {
+            name,
+            rounds,
+            joined: 1,
+            retired: None,
+            handicap: 0,
+        }
+    }
+
+    // a team joining part way through a season, starting with a lineup and a number of points
+    pub fn joining(name: String, round: u8, lineup: Vec<u8>, handicap: i16) -> Team {
+        let mut team = Team::from_rounds(name, HashMap::new());
+        team.joined = round;
+        team.handicap = handicap;
+        team.store_lineup(round, lineup);
+        team
+    }
+
+    // stops a team from taking part from a round onward, removing any lineups from then on
+    pub fn retire(&mut self, round: u8) {
+        self.retired = Some(round);
+        self.rounds.retain(|r, _| *r < round);
+    }
+
+    // whether the team has joined by a round, including teams that have since retired
+    pub fn has_joined(&self, round: u8) -> bool {
+        self.joined <= round
+    }
+
+    pub fn is_active(&self, round: u8) -> bool {
+        self.has_joined(round) && self.retired.is_none_or(|retired| round < retired)
+    }
+
+    pub fn get_joined(&self) -> u8 {
+        self.joined
+    }
+
+    pub fn get_retired(&self) -> Option<u8> {
+        self.retired
+    }
+
+    pub fn get_handicap(&self) -> i16 {
+        self.handicap
     }
 
     pub fn calculate_score(
@@ -66,10 +114,15 @@ impl Team {
         &self,
         round: u8,
         drafter: &mut dyn Drafter,
+        lineup_size: u8,
     ) -> Result<Vec<u8>, DraftError> {
         let prev_round_drivers = self.rounds.get(&(round - 1)).map(|x| x.lineup.as_slice());
 
-        drafter.draft(&self.name, prev_round_drivers)
+        let lineup = drafter.draft(&self.name, prev_round_drivers, lineup_size as usize)?;
+        if lineup.len() != lineup_size as usize {
+            return Err(DraftError::WrongLineupSize(round, lineup_size));
+        }
+        Ok(lineup)
     }
     pub fn store_lineup(&mut self, round: u8, lineup: Vec<u8>) {
         if self.rounds.contains_key(&round) {
@@ -77,22 +130,43 @@ impl Team {
         }
         self.rounds.insert(round, TeamRound::new(lineup));
     }
+    pub fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
     pub fn name(&self) -> String {
         self.name.clone()
     }
 
     pub fn get_points_by(&self, round: u8) -> i16 {
+        let handicap = if self.has_joined(round) {
+            self.handicap
+        } else {
+            0
+        };
         self.rounds
             .iter()
             .filter(|pair| pair.0 <= &round)
             .map(|pair| pair.1.points.unwrap_or_default())
             .sum::<i16>()
+            + handicap
     }
 
     pub fn get_points_at(&self, round: u8) -> Option<i16> {
         self.rounds.get(&round).and_then(|r| r.points)
     }
 
+    // every round this team has a lineup for, in order
+    pub fn get_rounds(&self) -> Vec<u8> {
+        let mut rounds: Vec<_> = self.rounds.keys().copied().collect();
+        rounds.sort();
+        rounds
+    }
+
+    pub fn has_lineup_at(&self, round: u8) -> bool {
+        self.rounds.contains_key(&round)
+    }
+
     pub fn get_lineup_at(&self, round: u8) -> Option<Vec<u8>> {
         self.rounds.get(&round).map(|r| r.lineup.clone())
     }
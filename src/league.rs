@@ -0,0 +1,260 @@
+pub mod error;
+pub mod honours;
+
+use crate::fantasy_season::FantasySeason;
+use error::LeagueError;
+use honours::{MemberRecord, SeasonHonours};
+use serde::{Deserialize, Serialize};
+
+// a league is run once a year, with one fantasy season for each year. Members are the people
+// behind the teams, so they can be followed across seasons even if their team names change
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct League {
+    name: String,
+    members: Vec<String>,
+    seasons: Vec<LeagueSeason>,
+}
+
+// a season is found by its id, so it stays in the league when renamed, and each of its teams may
+// belong to a member. Members are in the same order as the season's teams
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LeagueSeason {
+    id: u64,
+    year: u16,
+    members: Vec<Option<String>>,
+}
+
+impl League {
+    pub fn new(name: String) -> Result<League, LeagueError> {
+        if name.trim().is_empty() {
+            return Err(LeagueError::EmptyLeagueName);
+        }
+        Ok(League {
+            name,
+            members: Vec::new(),
+            seasons: Vec::new(),
+        })
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_members(&self) -> &[String] {
+        &self.members
+    }
+
+    pub fn add_member(&mut self, name: String) -> Result<(), LeagueError> {
+        if name.trim().is_empty() {
+            return Err(LeagueError::EmptyMemberName);
+        }
+        if self.members.contains(&name) {
+            return Err(LeagueError::DuplicateMemberName(name));
+        }
+        self.members.push(name);
+        Ok(())
+    }
+
+    // adds a season to the league, kept in year order. Teams named after a member are given to
+    // that member
+    pub fn add_season(&mut self, season: &FantasySeason) -> Result<(), LeagueError> {
+        if self.contains(season) {
+            return Err(LeagueError::SeasonAlreadyInLeague(
+                season.get_name().to_string(),
+            ));
+        }
+        if self.seasons.iter().any(|s| s.year == season.get_season()) {
+            return Err(LeagueError::SeasonYearAlreadyInLeague(season.get_season()));
+        }
+
+        let members = season
+            .get_team_names()
+            .into_iter()
+            .map(|team| self.members.contains(&team).then_some(team))
+            .collect();
+        let idx = self
+            .seasons
+            .partition_point(|s| s.year < season.get_season());
+        self.seasons.insert(
+            idx,
+            LeagueSeason {
+                id: season.get_id(),
+                year: season.get_season(),
+                members,
+            },
+        );
+        Ok(())
+    }
+
+    pub fn remove_season(&mut self, id: u64) {
+        self.seasons.retain(|s| s.id != id);
+    }
+
+    pub fn remove_season_at(&mut self, season: usize) {
+        self.seasons.remove(season);
+    }
+
+    pub fn contains(&self, season: &FantasySeason) -> bool {
+        self.seasons.iter().any(|s| s.id == season.get_id())
+    }
+
+    // the name and year of every season in the league, oldest first. A season that cannot be found
+    // has no name
+    pub fn get_seasons(&self, seasons: &[FantasySeason]) -> Vec<(Option<String>, u16)> {
+        self.seasons
+            .iter()
+            .map(|s| (s.find(seasons).map(|fs| fs.get_name().to_string()), s.year))
+            .collect()
+    }
+
+    // each team of a season in the league, along with its member if it has one
+    pub fn get_season_members(
+        &self,
+        season: usize,
+        seasons: &[FantasySeason],
+    ) -> Vec<(String, Option<String>)> {
+        let ls = &self.seasons[season];
+        ls.find(seasons)
+            .map(|fs| {
+                fs.get_team_names()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, team)| (team, ls.members.get(idx).cloned().flatten()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn set_member(&mut self, season: usize, team: usize, member: Option<String>) {
+        let members = &mut self.seasons[season].members;
+        if members.len() <= team {
+            members.resize(team + 1, None);
+        }
+        members[team] = member;
+    }
+
+    // the year after the latest season in the league
+    pub fn get_next_year(&self) -> Option<u16> {
+        self.seasons.last().map(|s| s.year + 1)
+    }
+
+    // the members in the order their teams should be entered into the next season, which is the
+    // reverse of the latest season's standings so that its tiebreakers favour the teams that did
+    // worst. Members who did not take part are entered before everyone else
+    pub fn get_seeding(&self, seasons: &[FantasySeason]) -> Vec<String> {
+        let mut ranked: Vec<String> = Vec::new();
+        for (member, _, _) in self
+            .seasons
+            .iter()
+            .rev()
+            .find_map(|ls| ls.standings(seasons))
+            .unwrap_or_default()
+            .into_iter()
+            .rev()
+        {
+            if let Some(member) = member.filter(|m| !ranked.contains(m)) {
+                ranked.push(member);
+            }
+        }
+
+        let mut seeding: Vec<String> = self
+            .members
+            .iter()
+            .filter(|m| !ranked.contains(m))
+            .cloned()
+            .collect();
+        seeding.append(&mut ranked);
+        seeding
+    }
+
+    // the top two of every season in the league that can still be found, newest first
+    pub fn get_honours(&self, seasons: &[FantasySeason]) -> Vec<SeasonHonours> {
+        self.seasons
+            .iter()
+            .rev()
+            .filter_map(|ls| {
+                let fs = ls.find(seasons)?;
+                let mut top = ls
+                    .standings(seasons)?
+                    .into_iter()
+                    .map(|(member, team, _)| member.unwrap_or(team));
+                Some(SeasonHonours {
+                    year: ls.year,
+                    season: fs.get_name().to_string(),
+                    champion: top.next(),
+                    runner_up: top.next(),
+                    complete: fs.is_complete(),
+                })
+            })
+            .collect()
+    }
+
+    // the all time record of every member, ordered by titles, then podiums, then points
+    pub fn get_records(&self, seasons: &[FantasySeason]) -> Vec<MemberRecord> {
+        let mut records: Vec<_> = self
+            .members
+            .iter()
+            .map(|member| MemberRecord {
+                member: member.clone(),
+                seasons: 0,
+                titles: 0,
+                podiums: 0,
+                points: 0,
+            })
+            .collect();
+
+        for ls in &self.seasons {
+            let Some(complete) = ls.find(seasons).map(FantasySeason::is_complete) else {
+                continue;
+            };
+            let standings = ls.standings(seasons).unwrap_or_default();
+            for (pos, (member, _, points)) in standings.into_iter().enumerate() {
+                let Some(record) = member.and_then(|m| records.iter_mut().find(|r| r.member == m))
+                else {
+                    continue;
+                };
+                record.seasons += 1;
+                record.points += points as i32;
+                if complete && pos == 0 {
+                    record.titles += 1;
+                }
+                if complete && pos < 3 {
+                    record.podiums += 1;
+                }
+            }
+        }
+
+        records.sort_by(|a, b| {
+            b.titles
+                .cmp(&a.titles)
+                .then(b.podiums.cmp(&a.podiums))
+                .then(b.points.cmp(&a.points))
+        });
+        records
+    }
+}
+
+impl LeagueSeason {
+    fn find<'a>(&self, seasons: &'a [FantasySeason]) -> Option<&'a FantasySeason> {
+        seasons.iter().find(|s| s.get_id() == self.id)
+    }
+
+    // the latest standings of the season as (member, team, points), first to last
+    fn standings(&self, seasons: &[FantasySeason]) -> Option<Vec<(Option<String>, String, i16)>> {
+        let season = self.find(seasons)?;
+        let teams = season.get_team_names();
+        Some(
+            season
+                .get_points_by(u8::MAX)
+                .into_iter()
+                .map(|(team, points)| {
+                    let member = teams
+                        .iter()
+                        .position(|t| *t == team)
+                        .and_then(|idx| self.members.get(idx).cloned().flatten());
+                    (member, team, points)
+                })
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,35 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+#[derive(Debug, Clone)]
+pub enum LeagueError {
+    EmptyLeagueName,
+    DuplicateLeagueName(String),
+    EmptyMemberName,
+    DuplicateMemberName(String),
+    SeasonAlreadyInLeague(String),
+    SeasonYearAlreadyInLeague(u16),
+}
+
+impl Display for LeagueError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LeagueError::EmptyLeagueName => write!(f, "the league must have a name"),
+            LeagueError::DuplicateLeagueName(name) => {
+                write!(f, "a league is already named {name}")
+            }
+            LeagueError::EmptyMemberName => write!(f, "every member must have a name"),
+            LeagueError::DuplicateMemberName(name) => {
+                write!(f, "a member is already named {name}")
+            }
+            LeagueError::SeasonAlreadyInLeague(name) => {
+                write!(f, "{name} is already part of the league")
+            }
+            LeagueError::SeasonYearAlreadyInLeague(year) => {
+                write!(f, "the league already has a season for {year}")
+            }
+        }
+    }
+}
+
+impl Error for LeagueError {}
@@ -0,0 +1,21 @@
+// the top two of a season in a league, by member where the team has one and by team otherwise.
+// Seasons that are not complete show their current leaders
+#[derive(Debug, Clone)]
+pub struct SeasonHonours {
+    pub year: u16,
+    pub season: String,
+    pub champion: Option<String>,
+    pub runner_up: Option<String>,
+    pub complete: bool,
+}
+
+// how a member has done across every season of a league. Titles and podiums only count complete
+// seasons
+#[derive(Debug, Clone)]
+pub struct MemberRecord {
+    pub member: String,
+    pub seasons: usize,
+    pub titles: usize,
+    pub podiums: usize,
+    pub points: i32,
+}
@@ -4,7 +4,11 @@ use iced::theme::Palette;
 use iced::{Font, Theme};
 
 mod api;
+mod cli;
+mod export;
 pub mod fantasy_season;
+mod league;
+mod save;
 mod vc;
 
 const F1_FONT: &[u8] = include_bytes!("../assets/Formula1-Regular.ttf");
@@ -12,24 +16,26 @@ const MONOSPACE_FONT: &[u8] = include_bytes!("../assets/IBMPlexMono-Bold.ttf");
 
 const SYMBOLS_FONT: &[u8] = include_bytes!("../assets/MaterialSymbolsRounded-Bold.ttf");
 
+const PALETTE: Palette = Palette {
+    background: iced::color!(0x423E3B),
+    text: iced::color!(0xFCF7F8),
+    primary: iced::color!(0xFF6319),
+    success: iced::color!(0xFFBD00),
+    danger: iced::color!(0xCE3636),
+};
+
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.is_empty() {
+        std::process::exit(cli::run(args));
+    }
+
     iced::application(
         "Formula One Fantasy Manager",
         ViewController::update,
         ViewController::view,
     )
-    .theme(|_| {
-        Theme::custom(
-            String::from("Classic West"),
-            Palette {
-                background: iced::color!(0x423E3B),
-                text: iced::color!(0xFCF7F8),
-                primary: iced::color!(0xFF6319),
-                success: iced::color!(0xFFBD00),
-                danger: iced::color!(0xCE3636),
-            },
-        )
-    })
+    .theme(|_| Theme::custom(String::from("Classic West"), PALETTE))
     .subscription(ViewController::subscription)
     .font(F1_FONT)
     .font(MONOSPACE_FONT)
@@ -0,0 +1,219 @@
+use crate::fantasy_season::FantasySeason;
+use crate::fantasy_season::legacy::FantasySeasonV1;
+use crate::league::League;
+use directories_next::ProjectDirs;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fs::File;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+// saves start with the version of their layout, which must go up whenever anything saved in them
+// changes, so a save is never read with the wrong layout
+const SAVE_FILE: &str = "seasons";
+const SAVE_VERSION: u16 = 11;
+const LEAGUE_FILE: &str = "leagues";
+const LEAGUE_VERSION: u16 = 2;
+// the seasons as saved before their layout was versioned
+const LEGACY_SAVE_FILE: &str = "seasons_v1";
+
+pub fn save_path() -> PathBuf {
+    PathBuf::from(ProjectDirs::from("com", "w13n", "F1FM").unwrap().data_dir())
+}
+
+// the saved seasons, or none if nothing has been saved yet. A save that exists but can't be read
+// is an error, so callers don't mistake it for an empty one
+pub fn load(save_path: &Path) -> std::io::Result<Vec<FantasySeason>> {
+    if let Some(seasons) = load_file(save_path, SAVE_FILE, Some(SAVE_VERSION))? {
+        return Ok(seasons);
+    }
+    Ok(
+        load_file::<FantasySeasonV1>(save_path, LEGACY_SAVE_FILE, None)?
+            .map(|v1| v1.into_iter().map(FantasySeason::from).collect())
+            .unwrap_or_default(),
+    )
+}
+
+pub fn load_leagues(save_path: &Path) -> std::io::Result<Vec<League>> {
+    Ok(load_file(save_path, LEAGUE_FILE, Some(LEAGUE_VERSION))?.unwrap_or_default())
+}
+
+pub fn store(save_path: &Path, seasons: &[&FantasySeason]) {
+    store_file(save_path, SAVE_FILE, SAVE_VERSION, seasons);
+}
+
+pub fn store_leagues(save_path: &Path, leagues: &[League]) {
+    store_file(save_path, LEAGUE_FILE, LEAGUE_VERSION, leagues);
+}
+
+// renames the seasons save that could not be loaded, so it is kept rather than replaced by the
+// next save. Returns where it was moved to
+pub fn set_aside(save_path: &Path) -> std::io::Result<Option<PathBuf>> {
+    set_aside_first(save_path, &[SAVE_FILE, LEGACY_SAVE_FILE])
+}
+
+pub fn set_aside_leagues(save_path: &Path) -> std::io::Result<Option<PathBuf>> {
+    set_aside_first(save_path, &[LEAGUE_FILE])
+}
+
+// renames the first of the files that exists, trying them in the order they are loaded
+fn set_aside_first(save_path: &Path, file_names: &[&str]) -> std::io::Result<Option<PathBuf>> {
+    for file_name in file_names {
+        for name in [file_name.to_string(), format!("{file_name}n")] {
+            let aside = save_path.join(format!("{name}_unreadable"));
+            match std::fs::rename(save_path.join(&name), &aside) {
+                Ok(()) => return Ok(Some(aside)),
+                Err(e) if e.kind() == ErrorKind::NotFound => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+    Ok(None)
+}
+
+// writes to the "n" copy first and then renames it, so a crash never leaves a half written save
+fn store_file<T: Serialize>(save_path: &Path, file_name: &str, version: u16, items: &[T]) {
+    if std::fs::create_dir_all(save_path).is_ok() {
+        let mut n_path = save_path.to_path_buf();
+        let mut path = save_path.to_path_buf();
+
+        n_path.push(format!("{file_name}n"));
+        path.push(file_name);
+
+        File::create(&n_path)
+            .unwrap()
+            .write_all(&postcard::to_stdvec(&(version, items)).unwrap())
+            .unwrap();
+
+        let _ = std::fs::rename(n_path, path);
+    }
+}
+
+// reads the save file with the given name. The "n" copy is only read when the save itself is
+// missing, which happens if the app stopped between writing the first save and renaming it. While
+// the app is running the "n" copy may be half written, so it is never preferred over the save.
+// Saves written with a version must have the expected one
+fn load_file<T: DeserializeOwned>(
+    save_path: &Path,
+    file_name: &str,
+    version: Option<u16>,
+) -> std::io::Result<Option<Vec<T>>> {
+    let bytes = match read_file(&save_path.join(file_name))? {
+        Some(bytes) => bytes,
+        None => match read_file(&save_path.join(format!("{file_name}n")))? {
+            Some(bytes) => bytes,
+            None => return Ok(None),
+        },
+    };
+    let invalid = |reason: String| {
+        std::io::Error::new(
+            ErrorKind::InvalidData,
+            format!("{file_name} could not be read: {reason}"),
+        )
+    };
+
+    let mut rest = bytes.as_slice();
+    if let Some(version) = version {
+        let (saved, remaining) =
+            postcard::take_from_bytes::<u16>(rest).map_err(|e| invalid(e.to_string()))?;
+        if saved != version {
+            return Err(invalid(format!(
+                "it has layout version {saved}, but only version {version} can be read"
+            )));
+        }
+        rest = remaining;
+    }
+    // postcard can decode a save written with a different layout without failing, so a save is
+    // only accepted if it decodes to exactly its length
+    let (items, remaining) = postcard::take_from_bytes(rest).map_err(|e| invalid(e.to_string()))?;
+    if !remaining.is_empty() {
+        return Err(invalid(format!(
+            "{} bytes were left over, so it was saved with a different layout",
+            remaining.len()
+        )));
+    }
+    Ok(Some(items))
+}
+
+// the contents of a file, or none if it doesn't exist
+fn read_file(path: &Path) -> std::io::Result<Option<Vec<u8>>> {
+    match std::fs::read(path) {
+        Ok(bytes) => Ok(Some(bytes)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a save written by the first release, with two teams drafted for two rounds and the first
+    // round scored
+    const V1_SAVE: &[u8] = include_bytes!("../testdata/seasons_v1");
+
+    // an empty directory to save into, unique to the test
+    fn save_dir(test: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("f1fm-{}-{test}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn loads_a_v1_save() {
+        let dir = save_dir("v1");
+        std::fs::write(dir.join(LEGACY_SAVE_FILE), V1_SAVE).unwrap();
+
+        let seasons = load(&dir).unwrap();
+        assert_eq!(seasons.len(), 1);
+        let season = &seasons[0];
+        assert_eq!(season.get_name(), "Office League");
+        assert_eq!(season.get_season(), 2024);
+        assert_eq!(season.get_lineup_size_at(1), 2);
+        assert_eq!(season.get_first_unscored_round(), 2);
+        assert_eq!(
+            season.get_points_by(1),
+            [(String::from("Alpha"), 37), (String::from("Bravo"), 33)]
+        );
+        assert!(season.get_results_at(2).is_some_and(|r| !r.is_manual()));
+    }
+
+    #[test]
+    fn loads_what_was_stored() {
+        let dir = save_dir("round-trip");
+        std::fs::write(dir.join(LEGACY_SAVE_FILE), V1_SAVE).unwrap();
+        let seasons = load(&dir).unwrap();
+
+        store(&dir, &seasons.iter().collect::<Vec<_>>());
+        let loaded = load(&dir).unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].get_id(), seasons[0].get_id());
+        assert_eq!(loaded[0].get_points_by(1), seasons[0].get_points_by(1));
+    }
+
+    #[test]
+    fn a_save_with_another_layout_is_an_error() {
+        let dir = save_dir("layout");
+        let mut older = postcard::to_stdvec(&SAVE_VERSION).unwrap();
+        older.extend_from_slice(V1_SAVE);
+        std::fs::write(dir.join(SAVE_FILE), older).unwrap();
+        assert!(load(&dir).is_err());
+
+        let mut newer = postcard::to_stdvec(&(SAVE_VERSION + 1)).unwrap();
+        newer.push(0);
+        std::fs::write(dir.join(SAVE_FILE), newer).unwrap();
+        assert!(load(&dir).is_err());
+    }
+
+    #[test]
+    fn an_unreadable_save_is_set_aside() {
+        let dir = save_dir("set-aside");
+        std::fs::write(dir.join(SAVE_FILE), b"not a save").unwrap();
+        assert!(load(&dir).is_err());
+
+        let aside = set_aside(&dir).unwrap();
+        assert_eq!(aside, Some(dir.join("seasons_unreadable")));
+        assert!(load(&dir).unwrap().is_empty());
+    }
+}
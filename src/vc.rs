@@ -1,19 +1,20 @@
 mod builder;
 mod landing;
+mod league;
 mod season;
 mod style;
 mod utils;
 
 use crate::fantasy_season::FantasySeason;
+use crate::league::League;
+use crate::save;
 use builder::{Builder, BuilderMessage};
-use directories_next::ProjectDirs;
 use iced::font::Weight;
 use iced::{Alignment, Element, Font, Length, Subscription, Task, widget};
 use landing::{Landing, LandingMessage};
+use league::{LeagueMessage, Leagues};
 use season::{Season, SeasonMessage};
 use std::fmt::Debug;
-use std::fs::File;
-use std::io::{Read, Write};
 use std::path::PathBuf;
 use std::time::Duration;
 
@@ -39,7 +40,11 @@ const SYMB_FONT: Font = {
 pub(super) struct ViewController {
     window: Window,
     seasons: Vec<FantasySeason>,
+    leagues: Vec<League>,
     save_path: PathBuf,
+    // off when a save could not be read or moved aside, so it isn't replaced by an empty one
+    save_seasons: bool,
+    save_leagues: bool,
 }
 
 impl Default for ViewController {
@@ -50,30 +55,25 @@ impl Default for ViewController {
 
 impl ViewController {
     pub(crate) fn new() -> ViewController {
-        let save_path = PathBuf::from(ProjectDirs::from("com", "w13n", "F1FM").unwrap().data_dir());
-
-        let mut seasons_path = save_path.clone();
-        seasons_path.push("seasons_v1n");
-        let mut seasons_file = Vec::with_capacity(100);
-
-        if std::fs::exists(&seasons_path).unwrap_or_default() {
-            let mut file = File::open(seasons_path).unwrap();
-            file.read_to_end(&mut seasons_file).unwrap();
-        } else {
-            seasons_path.pop();
-            seasons_path.push("seasons_v1");
-            if let Ok(mut file) = File::open(seasons_path) {
-                file.read_to_end(&mut seasons_file).unwrap();
-            }
-        }
+        ViewController::with_save_path(save::save_path())
+    }
 
-        let seasons: Vec<FantasySeason> = postcard::from_bytes(&seasons_file).unwrap_or_default();
+    fn with_save_path(save_path: PathBuf) -> ViewController {
+        let (seasons, save_seasons) = recover("seasons", save::load(&save_path), || {
+            save::set_aside(&save_path)
+        });
+        let (leagues, save_leagues) = recover("leagues", save::load_leagues(&save_path), || {
+            save::set_aside_leagues(&save_path)
+        });
         let season_names = seasons.iter().map(|s| String::from(s.get_name())).collect();
 
         ViewController {
             seasons,
+            leagues,
             window: Window::Landing(Landing::new(season_names)),
             save_path,
+            save_seasons,
+            save_leagues,
         }
     }
     pub fn view(&self) -> Element<'_, VCMessage> {
@@ -81,6 +81,9 @@ impl ViewController {
             Window::Season(season) => season.view().map(VCMessage::Season),
             Window::Builder(builder) => builder.view().map(VCMessage::Builder),
             Window::Landing(landing) => landing.view().map(VCMessage::Landing),
+            Window::Leagues(leagues) => leagues
+                .view(&self.leagues, &self.seasons)
+                .map(VCMessage::League),
         })
         .padding(PADDING)
         .into()
@@ -92,6 +95,7 @@ impl ViewController {
             Window::Season(s) => s.subscription().map(VCMessage::Season),
             Window::Builder(_) => Subscription::none(),
             Window::Landing(_) => Subscription::none(),
+            Window::Leagues(_) => Subscription::none(),
         };
 
         Subscription::batch(vec![save, window])
@@ -110,19 +114,11 @@ impl ViewController {
                     .chain(self.seasons.iter())
                     .collect();
 
-                if std::fs::create_dir_all(&self.save_path).is_ok() {
-                    let mut n_path = self.save_path.clone();
-                    let mut path = self.save_path.clone();
-
-                    n_path.push("seasons_v1n");
-                    path.push("seasons_v1");
-
-                    File::create(&n_path)
-                        .unwrap()
-                        .write_all(&postcard::to_stdvec(&all_seasons).unwrap())
-                        .unwrap();
-
-                    let _ = std::fs::rename(n_path, path);
+                if self.save_seasons {
+                    save::store(&self.save_path, &all_seasons);
+                }
+                if self.save_leagues {
+                    save::store_leagues(&self.save_path, &self.leagues);
                 }
                 Task::none()
             }
@@ -150,6 +146,14 @@ impl ViewController {
                     Task::none()
                 }
             }
+            VCMessage::League(lm) => {
+                if let Window::Leagues(l) = &mut self.window {
+                    let action = l.update(lm, &mut self.leagues, &self.seasons);
+                    self.handle_action(action)
+                } else {
+                    Task::none()
+                }
+            }
         }
     }
 
@@ -173,7 +177,7 @@ impl ViewController {
                         }
                         Task::none()
                     }
-                    Window::Builder(_b) => {
+                    Window::Builder(_) | Window::Leagues(_) => {
                         self.window = Window::Landing(Landing::new(
                             self.seasons
                                 .iter()
@@ -186,14 +190,18 @@ impl ViewController {
                 }
             }
             VCAction::OpenSeason(idx) => {
-                self.window = Window::Season(Season::new(self.seasons.remove(idx)));
+                let season = self.seasons.remove(idx);
+                self.window = Window::Season(Season::new(season));
                 Task::batch(vec![
                     Task::done(VCMessage::Season(SeasonMessage::DownloadFirstRace)),
-                    Task::done(VCMessage::Season(SeasonMessage::DownloadRaceNames)),
+                    Task::done(VCMessage::Season(SeasonMessage::DownloadSchedule)),
                 ])
             }
             VCAction::DeleteSeason(idx) => {
-                self.seasons.remove(idx);
+                let season = self.seasons.remove(idx);
+                self.leagues
+                    .iter_mut()
+                    .for_each(|l| l.remove_season(season.get_id()));
                 if let Window::Landing(l) = &mut self.window {
                     l.delete(idx);
                 }
@@ -203,12 +211,45 @@ impl ViewController {
                 self.window = Window::Builder(Builder::new());
                 Task::none()
             }
+            VCAction::CopySeason(idx, reverse_standings) => {
+                let season = &self.seasons[idx];
+                let league = self
+                    .leagues
+                    .iter()
+                    .find(|l| l.contains(season))
+                    .map(|l| l.get_name().to_string());
+                self.window =
+                    Window::Builder(Builder::from_season(season, reverse_standings, league));
+                Task::none()
+            }
+            VCAction::OpenLeagues => {
+                self.window = Window::Leagues(Leagues::new());
+                Task::none()
+            }
+            VCAction::OpenBuilderForLeague(idx) => {
+                let league = &self.leagues[idx];
+                self.window = Window::Builder(Builder::for_league(
+                    league.get_name().to_string(),
+                    league.get_seeding(&self.seasons),
+                    league.get_next_year(),
+                ));
+                Task::none()
+            }
             VCAction::CreateFromBuilder => match &mut self.window {
                 Window::Builder(b) => {
-                    self.window = Window::Season(Season::new(b.create()));
+                    let season = b.create();
+                    // a season for a year the league already has is left out of the league
+                    if let Some(league) = self
+                        .leagues
+                        .iter_mut()
+                        .find(|l| Some(l.get_name()) == b.get_league())
+                    {
+                        let _ = league.add_season(&season);
+                    }
+                    self.window = Window::Season(Season::new(season));
                     Task::batch(vec![
                         Task::done(VCMessage::Season(SeasonMessage::DownloadFirstRace)),
-                        Task::done(VCMessage::Season(SeasonMessage::DownloadRaceNames)),
+                        Task::done(VCMessage::Season(SeasonMessage::DownloadSchedule)),
                     ])
                 }
                 _ => {
@@ -226,6 +267,7 @@ enum Window {
     Season(Season),
     Builder(Builder),
     Landing(Landing),
+    Leagues(Leagues),
 }
 
 #[derive(Debug, Clone)]
@@ -234,13 +276,17 @@ pub enum VCMessage {
     Season(SeasonMessage),
     Builder(BuilderMessage),
     Landing(LandingMessage),
+    League(LeagueMessage),
 }
 
 pub enum VCAction {
     WindowExit,
     OpenSeason(usize),
+    CopySeason(usize, bool),
     DeleteSeason(usize),
     OpenBuilder,
+    OpenLeagues,
+    OpenBuilderForLeague(usize),
     CreateFromBuilder,
     Task(Task<VCMessage>),
     None,
@@ -269,3 +315,81 @@ fn top_row<T: Debug + Clone + 'static>(title: String, font: Font, exit: T) -> El
     ]
     .into()
 }
+
+// a box to paste lineups into, one team per line in the form `team,driver1,driver2,...`, along
+// with why the lineups cannot be used if they cannot
+fn import_view<'a, T: Clone + 'a>(
+    content: &'a widget::text_editor::Content,
+    on_edit: impl Fn(widget::text_editor::Action) -> T + 'a,
+    import: T,
+    error: Option<String>,
+) -> Element<'a, T> {
+    widget::column![
+        widget::text_editor(content)
+            .placeholder("paste lineups here, one team per line: team,driver1,driver2,...")
+            .on_action(on_edit)
+            .size(CONTENT)
+            .height(100),
+        widget::row![
+            widget::text!("{}", error.unwrap_or_default())
+                .size(CONTENT)
+                .style(widget::text::danger)
+                .width(Length::Fill),
+            widget::button(widget::text!("import").size(CONTENT))
+                .on_press(import)
+                .style(style::button::secondary),
+        ]
+        .spacing(PADDING)
+        .align_y(Alignment::Center),
+    ]
+    .spacing(PADDING)
+    .into()
+}
+
+// the loaded save, or nothing if it could not be read, along with whether it is safe to save over.
+// An unreadable save is moved aside so it is kept, and if that fails it must not be saved over
+fn recover<T>(
+    what: &str,
+    loaded: std::io::Result<Vec<T>>,
+    set_aside: impl FnOnce() -> std::io::Result<Option<PathBuf>>,
+) -> (Vec<T>, bool) {
+    let err = match loaded {
+        Ok(items) => return (items, true),
+        Err(err) => err,
+    };
+    eprintln!("error: the saved {what} could not be loaded: {err}");
+    match set_aside() {
+        Ok(Some(path)) => {
+            eprintln!("the unreadable save was kept at {}", path.display());
+            (Vec::new(), true)
+        }
+        Ok(None) => (Vec::new(), true),
+        Err(err) => {
+            eprintln!(
+                "error: the unreadable save could not be moved aside, so {what} will not be saved: {err}"
+            );
+            (Vec::new(), false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn an_unreadable_save_that_cannot_be_moved_is_not_saved_over() {
+        let dir = std::env::temp_dir().join(format!("f1fm-{}-vc-save", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        // a directory where the save would be moved to makes moving it fail
+        std::fs::create_dir_all(dir.join("seasons_unreadable").join("taken")).unwrap();
+        std::fs::write(dir.join("seasons"), b"not a save").unwrap();
+
+        let mut vc = ViewController::with_save_path(dir.clone());
+        assert!(vc.seasons.is_empty());
+        let _ = vc.update(VCMessage::Save);
+
+        assert_eq!(std::fs::read(dir.join("seasons")).unwrap(), b"not a save");
+        assert!(dir.join("leagues").exists());
+    }
+}
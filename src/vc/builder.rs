@@ -1,8 +1,13 @@
-use super::utils::{is_parsable_driver, is_unique_lineups, is_valid_driver_input};
+use super::utils::{find_duplicate, is_parsable_driver, is_unique_lineups, is_valid_driver_input};
 use crate::fantasy_season::FantasySeason;
-use crate::fantasy_season::draft::{DraftChoice, ReplaceAll};
+use crate::fantasy_season::draft;
+use crate::fantasy_season::draft::{
+    DraftChoice, DraftSchedule, ReplaceAll, RollOnChoice, ScheduleChoice,
+};
+use crate::fantasy_season::error::ImportError;
 use crate::fantasy_season::score::ScoreChoice;
 use crate::vc::{CONTENT, MONO_FONT, PADDING, SYMB_FONT, VCAction, style};
+use iced::widget::text_editor;
 use iced::{Alignment, Element, Length, widget};
 use time::OffsetDateTime;
 
@@ -14,10 +19,16 @@ pub(super) struct Builder {
     teams: Vec<TeamBuilder>,
     score_choice: Option<ScoreChoice>,
     draft_choice: Option<DraftChoice>,
+    roll_on_choice: RollOnChoice,
+    schedule_choice: ScheduleChoice,
+    schedule_input: String,
     season: String,
     grid_size: String,
     team_size: u8,
     enforce_uniqueness: bool,
+    league: Option<String>,
+    import: text_editor::Content,
+    import_error: Option<String>,
 }
 
 impl Builder {
@@ -31,18 +42,94 @@ impl Builder {
             ],
             score_choice: None,
             draft_choice: None,
+            roll_on_choice: RollOnChoice::default(),
+            schedule_choice: ScheduleChoice::default(),
+            schedule_input: String::new(),
             season: (OffsetDateTime::now_utc().year() as u16).to_string(),
             grid_size: GRID_SIZE_DEFAULT.to_string(),
             team_size: TEAM_SIZE_DEFAULT,
             enforce_uniqueness: true,
+            league: None,
+            import: text_editor::Content::new(),
+            import_error: None,
         }
     }
 
+    // a builder for the next season of a league, with a team for each member in seeding order
+    pub fn for_league(league: String, members: Vec<String>, season: Option<u16>) -> Builder {
+        let mut builder = Builder::new();
+        if !members.is_empty() {
+            builder.teams = named_teams(members, TEAM_SIZE_DEFAULT);
+        }
+        if let Some(season) = season {
+            builder.season = season.to_string();
+        }
+        builder.league = Some(league);
+        builder
+    }
+
+    // a builder for the year after an existing season, with the same settings and the teams that
+    // had not retired, either in the order they were created or in reverse final standings order.
+    // Lineups are left empty, as they are drafted again
+    pub fn from_season(
+        fantasy_season: &FantasySeason,
+        reverse_standings: bool,
+        league: Option<String>,
+    ) -> Builder {
+        let settings = fantasy_season.get_settings(u8::MAX);
+        let year = fantasy_season.get_season();
+        let next_year = year.saturating_add(1);
+
+        let remaining: Vec<_> = fantasy_season
+            .get_team_memberships()
+            .into_iter()
+            .filter(|(_, _, retired)| retired.is_none())
+            .map(|(name, _, _)| name)
+            .collect();
+        let team_names = if reverse_standings {
+            fantasy_season
+                .get_points_by(u8::MAX)
+                .into_iter()
+                .rev()
+                .map(|(name, _)| name)
+                .filter(|name| remaining.contains(name))
+                .collect()
+        } else {
+            remaining
+        };
+
+        Builder {
+            name: settings
+                .name
+                .replace(&year.to_string(), &next_year.to_string()),
+            teams: named_teams(team_names, settings.lineup_size),
+            score_choice: Some(fantasy_season.get_score_choice()),
+            draft_choice: Some(settings.draft_choice),
+            roll_on_choice: settings.roll_on_choice,
+            schedule_choice: settings.draft_schedule.choice(),
+            schedule_input: settings.draft_schedule.input(),
+            season: next_year.to_string(),
+            grid_size: settings.grid_size.to_string(),
+            team_size: settings.lineup_size,
+            enforce_uniqueness: settings.enforce_uniqueness,
+            league,
+            import: text_editor::Content::new(),
+            import_error: None,
+        }
+    }
+
+    pub fn get_league(&self) -> Option<&str> {
+        self.league.as_deref()
+    }
+
     pub fn update(&mut self, message: BuilderMessage) -> VCAction {
         match message {
             BuilderMessage::ChangeName(name) => self.name = name,
             BuilderMessage::ScoreChoiceSelected(choice) => self.score_choice = Some(choice),
             BuilderMessage::DraftChoiceSelected(choice) => self.draft_choice = Some(choice),
+            BuilderMessage::RollOnChoiceSelected(choice) => self.roll_on_choice = choice,
+            BuilderMessage::ScheduleChoiceSelected(choice) => self.schedule_choice = choice,
+            BuilderMessage::ChangeScheduleInput(input) => self.schedule_input = input,
             BuilderMessage::AddTeam => self
                 .teams
                 .push(TeamBuilder::new(self.teams.len(), self.team_size)),
@@ -81,6 +168,15 @@ impl Builder {
                 .get_mut(id)
                 .expect("id out of sync")
                 .set_name(name),
+            BuilderMessage::EditImport(action) => {
+                if action.is_edit() {
+                    self.import_error = None;
+                }
+                self.import.perform(action);
+            }
+            BuilderMessage::Import => {
+                self.import_error = self.import_teams().err().map(|ie| ie.to_string());
+            }
             BuilderMessage::Exit => return VCAction::WindowExit,
             BuilderMessage::Build => return VCAction::CreateFromBuilder,
         }
@@ -90,7 +186,10 @@ impl Builder {
 
     pub fn view(&self) -> Element<'_, BuilderMessage> {
         let top_row = crate::vc::top_row(
-            "build new season".to_string(),
+            match &self.league {
+                Some(league) => format!("build new season for {league}"),
+                None => "build new season".to_string(),
+            },
             MONO_FONT,
             BuilderMessage::Exit,
         );
@@ -100,7 +199,28 @@ impl Builder {
             widget::Column::from_vec(self.teams.iter().map(|t| t.view()).collect()).spacing(10),
         ))
         .max_height(300);
+        // lineups that break uniqueness are explained, as they otherwise only disable building
+        let import_error = self.import_error.clone().or_else(|| {
+            self.enforce_uniqueness
+                .then(|| {
+                    find_duplicate(
+                        self.teams
+                            .iter()
+                            .flat_map(|t| t.iter())
+                            .filter(|d| !d.is_empty()),
+                    )
+                })
+                .flatten()
+                .map(|d| format!("driver #{d} is in more than one lineup"))
+        });
+        let import = crate::vc::import_view(
+            &self.import,
+            BuilderMessage::EditImport,
+            BuilderMessage::Import,
+            import_error,
+        );
         let modes = self.view_modes();
+        let schedule = self.view_schedule();
         let season_and_grid_size = self.view_season_and_grid_size();
         let uniqueness = widget::row![
             widget::toggler(self.enforce_uniqueness)
@@ -117,13 +237,17 @@ impl Builder {
         let content = widget::column![
             team_settings,
             teams,
+            import,
             widget::vertical_space().height(PADDING),
             modes,
-            season_and_grid_size,
-            uniqueness,
-            widget::vertical_space().height(PADDING),
-            name,
         ]
+        .push_maybe(schedule)
+        .push(season_and_grid_size)
+        .extend([
+            uniqueness.into(),
+            widget::vertical_space().height(PADDING).into(),
+            name.into(),
+        ])
         .spacing(PADDING)
         .width(Length::Shrink)
         .align_x(Alignment::Center);
@@ -199,11 +323,85 @@ impl Builder {
         .menu_style(style::pick_list::default_menu)
         .text_size(CONTENT);
 
-        widget::row![score_mode, draft_mode,]
+        let roll_on_mode = (self.draft_choice == Some(DraftChoice::RollOn)).then(|| {
+            widget::pick_list(
+                vec![
+                    RollOnChoice::DropLast,
+                    RollOnChoice::TeamChooses,
+                    RollOnChoice::DropLowestScoring,
+                    RollOnChoice::DropLongestHeld,
+                ],
+                Some(self.roll_on_choice),
+                BuilderMessage::RollOnChoiceSelected,
+            )
+            .style(style::pick_list::default)
+            .menu_style(style::pick_list::default_menu)
+            .text_size(CONTENT)
+        });
+
+        widget::row![score_mode, draft_mode]
+            .push_maybe(roll_on_mode)
             .spacing(PADDING)
             .height(Length::Shrink)
     }
 
+    // only seasons that draft after the first round have a draft schedule
+    fn view_schedule(&self) -> Option<widget::Row<'_, BuilderMessage>> {
+        if self
+            .draft_choice
+            .is_none_or(|choice| choice == DraftChoice::Skip)
+        {
+            return None;
+        }
+
+        let schedule = widget::pick_list(
+            vec![
+                ScheduleChoice::EveryRound,
+                ScheduleChoice::EveryNRounds,
+                ScheduleChoice::AtRounds,
+            ],
+            Some(self.schedule_choice),
+            BuilderMessage::ScheduleChoiceSelected,
+        )
+        .style(style::pick_list::default)
+        .menu_style(style::pick_list::default_menu)
+        .text_size(CONTENT);
+
+        let input = match self.schedule_choice {
+            ScheduleChoice::EveryRound => None,
+            ScheduleChoice::EveryNRounds => Some("rounds between drafts"),
+            ScheduleChoice::AtRounds => Some("rounds to draft, such as 5, 12"),
+        }
+        .map(|placeholder| {
+            widget::text_input(placeholder, &self.schedule_input)
+                .on_input(BuilderMessage::ChangeScheduleInput)
+                .style(style::text_input::default)
+                .size(CONTENT)
+                .width(220)
+        });
+
+        Some(
+            widget::row![
+                widget::text!("Draft ")
+                    .height(Length::Fill)
+                    .size(CONTENT)
+                    .align_y(Alignment::Center),
+                schedule,
+            ]
+            .push_maybe(input)
+            .spacing(PADDING)
+            .height(Length::Shrink),
+        )
+    }
+
+    // skip seasons never use their schedule, so it cannot stop them from being created
+    fn get_schedule(&self) -> Option<DraftSchedule> {
+        match self.draft_choice {
+            Some(DraftChoice::Skip) => Some(DraftSchedule::EveryRound),
+            _ => DraftSchedule::parse(self.schedule_choice, &self.schedule_input),
+        }
+    }
+
     fn view_season_and_grid_size(&self) -> widget::Row<'_, BuilderMessage> {
         widget::row![
             widget::text_input("grid size", &self.grid_size)
@@ -230,6 +428,8 @@ impl Builder {
             self.name.clone(),
             self.score_choice.unwrap(),
             self.draft_choice.unwrap(),
+            self.roll_on_choice,
+            self.get_schedule().expect("cannot call create"),
             self.teams.iter().map(|team| team.get_name()),
             self.team_size,
             self.season.parse::<u16>().expect("cannot call create"),
@@ -251,6 +451,29 @@ impl Builder {
         fantasy_season
     }
 
+    // replaces every team with the teams pasted into the import box, with the lineup size of
+    // their lineups
+    fn import_teams(&mut self) -> Result<(), ImportError> {
+        let lineups = draft::parse_lineups(&self.import.text())?;
+        let lineup_size = lineups.first().map_or(0, |(_, drivers)| drivers.len());
+        draft::check_lineups(&lineups, None, lineup_size, self.enforce_uniqueness)?;
+        if lineup_size == 0 {
+            return Err(ImportError::EmptyLineup(lineups[0].0.clone()));
+        }
+
+        self.team_size = lineup_size as u8;
+        self.teams = named_teams(
+            lineups.iter().map(|(team, _)| team.clone()).collect(),
+            self.team_size,
+        );
+        for (team, (_, drivers)) in self.teams.iter_mut().zip(lineups) {
+            for (idx, driver) in drivers.into_iter().enumerate() {
+                team.change_driver(idx, driver.to_string());
+            }
+        }
+        Ok(())
+    }
+
     fn can_create(&self) -> bool {
         self.teams.iter().all(TeamBuilder::can_parse)
             && !self.teams.is_empty()
@@ -258,14 +481,31 @@ impl Builder {
                 || is_unique_lineups(self.teams.iter().flat_map(|x| x.iter())))
             && self.score_choice.is_some()
             && self.draft_choice.is_some()
+            && self.get_schedule().is_some()
     }
 }
 
+// a team for each name, with empty lineups
+fn named_teams(names: Vec<String>, team_size: u8) -> Vec<TeamBuilder> {
+    names
+        .into_iter()
+        .enumerate()
+        .map(|(id, name)| {
+            let mut team = TeamBuilder::new(id, team_size);
+            team.set_name(name);
+            team
+        })
+        .collect()
+}
+
 #[derive(Clone, Debug)]
 pub enum BuilderMessage {
     ChangeName(String),
     ScoreChoiceSelected(ScoreChoice),
     DraftChoiceSelected(DraftChoice),
+    RollOnChoiceSelected(RollOnChoice),
+    ScheduleChoiceSelected(ScheduleChoice),
+    ChangeScheduleInput(String),
     AddTeam,
     DeleteTeam(usize),
     IncreaseTeamSize,
@@ -275,6 +515,8 @@ pub enum BuilderMessage {
     ChangeSeason(String),
     ChangeGridSize(String),
     ToggleEnforceUniqueness(bool),
+    EditImport(text_editor::Action),
+    Import,
     Exit,
     Build,
 }
@@ -5,18 +5,25 @@ use super::{PADDING, style};
 
 pub struct Landing {
     season_names: Vec<String>,
+    reverse_standings: bool,
 }
 
 #[derive(Clone, Debug)]
 pub enum LandingMessage {
     OpenBuilder,
+    OpenLeagues,
     OpenSeason(usize),
+    CopySeason(usize),
+    ToggleReverseStandings(bool),
     DeleteSeason(usize),
 }
 
 impl Landing {
     pub fn new(season_names: Vec<String>) -> Landing {
-        Landing { season_names }
+        Landing {
+            season_names,
+            reverse_standings: true,
+        }
     }
 
     pub fn delete(&mut self, element: usize) {
@@ -33,6 +40,11 @@ impl Landing {
                             widget::Button::new(widget::text!("{}", name).size(CONTENT))
                                 .on_press(LandingMessage::OpenSeason(pos))
                                 .style(style::button::success),
+                            widget::Button::new(
+                                widget::text!("\u{e14d}").font(SYMB_FONT).size(CONTENT)
+                            )
+                            .on_press(LandingMessage::CopySeason(pos))
+                            .style(style::button::secondary),
                             widget::Button::new(
                                 widget::text!("\u{e872}").font(SYMB_FONT).size(CONTENT)
                             )
@@ -54,9 +66,20 @@ impl Landing {
                 .height(TITLE + PADDING * 4)
                 .align_y(Alignment::Center),
             content.height(Length::Fill),
-            widget::Button::new(widget::text!("build new season").size(CONTENT))
-                .on_press(LandingMessage::OpenBuilder)
-                .style(style::button::primary)
+            widget::toggler(self.reverse_standings)
+                .label("copy teams in reverse standings order")
+                .on_toggle(LandingMessage::ToggleReverseStandings)
+                .text_size(CONTENT)
+                .size(CONTENT),
+            widget::row![
+                widget::Button::new(widget::text!("build new season").size(CONTENT))
+                    .on_press(LandingMessage::OpenBuilder)
+                    .style(style::button::primary),
+                widget::Button::new(widget::text!("leagues").size(CONTENT))
+                    .on_press(LandingMessage::OpenLeagues)
+                    .style(style::button::secondary),
+            ]
+            .spacing(PADDING)
         ]
         .spacing(PADDING)
         .width(Length::Fill)
@@ -67,7 +90,13 @@ impl Landing {
     pub fn update(&mut self, message: LandingMessage) -> VCAction {
         match message {
             LandingMessage::OpenBuilder => VCAction::OpenBuilder,
+            LandingMessage::OpenLeagues => VCAction::OpenLeagues,
             LandingMessage::OpenSeason(pos) => VCAction::OpenSeason(pos),
+            LandingMessage::CopySeason(pos) => VCAction::CopySeason(pos, self.reverse_standings),
+            LandingMessage::ToggleReverseStandings(reverse) => {
+                self.reverse_standings = reverse;
+                VCAction::None
+            }
             LandingMessage::DeleteSeason(pos) => VCAction::DeleteSeason(pos),
         }
     }
@@ -0,0 +1,366 @@
+use super::{CONTENT, MONO_FONT, PADDING, SYMB_FONT, VCAction, style};
+use crate::fantasy_season::FantasySeason;
+use crate::league::League;
+use crate::league::error::LeagueError;
+use iced::widget::text::{danger, secondary};
+use iced::{Alignment, Element, Length, widget};
+use std::fmt::Display;
+
+pub(super) struct Leagues {
+    selected: Option<usize>,
+    league_name: String,
+    member_name: String,
+    error: Option<String>,
+}
+
+impl Leagues {
+    pub fn new() -> Leagues {
+        Leagues {
+            selected: None,
+            league_name: String::new(),
+            member_name: String::new(),
+            error: None,
+        }
+    }
+
+    pub fn view<'a>(
+        &'a self,
+        leagues: &'a [League],
+        seasons: &'a [FantasySeason],
+    ) -> Element<'a, LeagueMessage> {
+        let top_row = super::top_row("leagues".to_string(), MONO_FONT, LeagueMessage::Exit);
+
+        let league_list = widget::Column::from_iter(leagues.iter().enumerate().map(|(idx, l)| {
+            widget::row![
+                widget::button(widget::text!("{}", l.get_name()).size(CONTENT))
+                    .on_press(LeagueMessage::SelectLeague(idx))
+                    .style(if self.selected == Some(idx) {
+                        style::button::primary
+                    } else {
+                        style::button::success
+                    }),
+                widget::button(widget::text!("\u{e872}").font(SYMB_FONT).size(CONTENT))
+                    .on_press(LeagueMessage::DeleteLeague(idx))
+                    .style(style::button::danger),
+            ]
+            .spacing(PADDING)
+            .into()
+        }))
+        .spacing(PADDING);
+
+        let sidebar = widget::column![
+            widget::scrollable(league_list).height(Length::Fill),
+            widget::text_input("league name", &self.league_name)
+                .on_input(LeagueMessage::ChangeLeagueName)
+                .size(CONTENT)
+                .style(style::text_input::default),
+            widget::button(widget::text!("create league").size(CONTENT))
+                .on_press(LeagueMessage::CreateLeague)
+                .style(style::button::primary),
+        ]
+        .spacing(PADDING)
+        .width(300);
+
+        let details = match self.selected.and_then(|idx| leagues.get(idx)) {
+            Some(league) => self.view_league(league, seasons),
+            None => widget::text!("select or create a league")
+                .size(CONTENT)
+                .style(secondary)
+                .into(),
+        };
+
+        widget::column![
+            top_row,
+            widget::row![
+                sidebar,
+                widget::container(details)
+                    .padding(PADDING)
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .style(style::container::content),
+            ]
+            .spacing(PADDING)
+            .height(Length::Fill),
+            widget::text!("{}", self.error.as_deref().unwrap_or_default())
+                .size(CONTENT)
+                .style(danger),
+        ]
+        .spacing(PADDING)
+        .align_x(Alignment::Center)
+        .into()
+    }
+
+    fn view_league<'a>(
+        &'a self,
+        league: &'a League,
+        seasons: &'a [FantasySeason],
+    ) -> Element<'a, LeagueMessage> {
+        let members = widget::column![
+            widget::text!("members").size(CONTENT).style(secondary),
+            widget::text!("{}", league.get_members().join(", ")).size(CONTENT),
+            widget::row![
+                widget::text_input("member name", &self.member_name)
+                    .on_input(LeagueMessage::ChangeMemberName)
+                    .size(CONTENT)
+                    .width(300)
+                    .style(style::text_input::default),
+                widget::button(widget::text!("add member").size(CONTENT))
+                    .on_press(LeagueMessage::AddMember)
+                    .style(style::button::secondary),
+            ]
+            .spacing(PADDING),
+        ]
+        .spacing(PADDING);
+
+        let member_options: Vec<_> = std::iter::once(MemberOption(None))
+            .chain(
+                league
+                    .get_members()
+                    .iter()
+                    .map(|m| MemberOption(Some(m.clone()))),
+            )
+            .collect();
+
+        let mut league_seasons =
+            widget::column![widget::text!("seasons").size(CONTENT).style(secondary)]
+                .spacing(PADDING);
+        for (idx, (name, year)) in league.get_seasons(seasons).into_iter().enumerate() {
+            league_seasons = league_seasons.push(
+                widget::row![
+                    widget::text!("{year}  {}", name.unwrap_or_default()).size(CONTENT),
+                    widget::button(widget::text!("remove").size(CONTENT))
+                        .on_press(LeagueMessage::RemoveSeason(idx))
+                        .style(style::button::danger),
+                ]
+                .spacing(PADDING)
+                .align_y(Alignment::Center),
+            );
+
+            let teams = league.get_season_members(idx, seasons);
+            if teams.is_empty() {
+                league_seasons = league_seasons.push(
+                    widget::text!("this season is open or no longer exists")
+                        .size(CONTENT)
+                        .style(secondary),
+                );
+            }
+            for (team_idx, (team, member)) in teams.into_iter().enumerate() {
+                league_seasons = league_seasons.push(
+                    widget::row![
+                        widget::text!("{team}").size(CONTENT).width(300),
+                        widget::pick_list(
+                            member_options.clone(),
+                            Some(MemberOption(member)),
+                            move |m| LeagueMessage::SetMember(idx, team_idx, m),
+                        )
+                        .style(style::pick_list::default)
+                        .menu_style(style::pick_list::default_menu)
+                        .text_size(CONTENT),
+                    ]
+                    .spacing(PADDING)
+                    .align_y(Alignment::Center),
+                );
+            }
+        }
+
+        let season_options: Vec<_> = seasons
+            .iter()
+            .filter(|s| !league.contains(s))
+            .map(|s| SeasonOption {
+                id: s.get_id(),
+                name: s.get_name().to_string(),
+                year: s.get_season(),
+            })
+            .collect();
+        league_seasons = league_seasons.push(
+            widget::row![
+                widget::pick_list(season_options, None::<SeasonOption>, |s| {
+                    LeagueMessage::AddSeason(s)
+                })
+                .placeholder("add a season")
+                .style(style::pick_list::default)
+                .menu_style(style::pick_list::default_menu)
+                .text_size(CONTENT),
+                widget::button(widget::text!("start next season").size(CONTENT))
+                    .on_press(LeagueMessage::NextSeason)
+                    .style(style::button::primary),
+            ]
+            .spacing(PADDING),
+        );
+
+        let mut honours = widget::column![
+            widget::text!("honours").size(CONTENT).style(secondary),
+            widget::text!(
+                "{:<6}{:<30}{:<25}{:<25}",
+                "year",
+                "season",
+                "champion",
+                "runner up"
+            )
+            .size(CONTENT)
+            .style(secondary),
+        ];
+        for h in league.get_honours(seasons) {
+            honours = honours.push(
+                widget::text!(
+                    "{:<6}{:<30}{:<25}{:<25}{}",
+                    h.year,
+                    h.season,
+                    h.champion.unwrap_or_default(),
+                    h.runner_up.unwrap_or_default(),
+                    if h.complete { "" } else { "in progress" }
+                )
+                .size(CONTENT),
+            );
+        }
+
+        let mut records = widget::column![
+            widget::text!("all time").size(CONTENT).style(secondary),
+            widget::text!(
+                "{:<25}{:>8}{:>9}{:>9}{:>8}",
+                "member",
+                "titles",
+                "podiums",
+                "seasons",
+                "points"
+            )
+            .size(CONTENT)
+            .style(secondary),
+        ];
+        for r in league.get_records(seasons) {
+            records = records.push(
+                widget::text!(
+                    "{:<25}{:>8}{:>9}{:>9}{:>8}",
+                    r.member,
+                    r.titles,
+                    r.podiums,
+                    r.seasons,
+                    r.points
+                )
+                .size(CONTENT),
+            );
+        }
+
+        widget::scrollable(
+            widget::column![
+                widget::text!("{}", league.get_name()).size(CONTENT),
+                members,
+                league_seasons,
+                honours,
+                records,
+            ]
+            .spacing(PADDING * 3)
+            .width(Length::Fill),
+        )
+        .into()
+    }
+
+    pub fn update(
+        &mut self,
+        message: LeagueMessage,
+        leagues: &mut Vec<League>,
+        seasons: &[FantasySeason],
+    ) -> VCAction {
+        self.error = None;
+        let selected = self.selected.filter(|idx| *idx < leagues.len());
+
+        let result = match (message, selected) {
+            (LeagueMessage::Exit, _) => return VCAction::WindowExit,
+            (LeagueMessage::ChangeLeagueName(name), _) => {
+                self.league_name = name;
+                Ok(())
+            }
+            (LeagueMessage::CreateLeague, _) => {
+                if leagues.iter().any(|l| l.get_name() == self.league_name) {
+                    Err(LeagueError::DuplicateLeagueName(self.league_name.clone()))
+                } else {
+                    League::new(std::mem::take(&mut self.league_name)).map(|league| {
+                        leagues.push(league);
+                        self.selected = Some(leagues.len() - 1);
+                    })
+                }
+            }
+            (LeagueMessage::SelectLeague(idx), _) => {
+                self.selected = Some(idx);
+                Ok(())
+            }
+            (LeagueMessage::DeleteLeague(idx), _) => {
+                leagues.remove(idx);
+                self.selected = match self.selected {
+                    Some(s) if s == idx => None,
+                    Some(s) if s > idx => Some(s - 1),
+                    s => s,
+                };
+                Ok(())
+            }
+            (LeagueMessage::ChangeMemberName(name), _) => {
+                self.member_name = name;
+                Ok(())
+            }
+            (LeagueMessage::AddMember, Some(idx)) => leagues[idx]
+                .add_member(self.member_name.clone())
+                .map(|_| self.member_name.clear()),
+            (LeagueMessage::AddSeason(option), Some(idx)) => seasons
+                .iter()
+                .find(|s| s.get_id() == option.id)
+                .map_or(Ok(()), |season| leagues[idx].add_season(season)),
+            (LeagueMessage::RemoveSeason(season), Some(idx)) => {
+                leagues[idx].remove_season_at(season);
+                Ok(())
+            }
+            (LeagueMessage::SetMember(season, team, member), Some(idx)) => {
+                leagues[idx].set_member(season, team, member.0);
+                Ok(())
+            }
+            (LeagueMessage::NextSeason, Some(idx)) => return VCAction::OpenBuilderForLeague(idx),
+            _ => panic!("IMPOSSIBLE: LEAGUE MSG PASSED WITHOUT A SELECTED LEAGUE"),
+        };
+
+        if let Err(le) = result {
+            self.error = Some(le.to_string());
+        }
+        VCAction::None
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum LeagueMessage {
+    Exit,
+    ChangeLeagueName(String),
+    CreateLeague,
+    SelectLeague(usize),
+    DeleteLeague(usize),
+    ChangeMemberName(String),
+    AddMember,
+    AddSeason(SeasonOption),
+    RemoveSeason(usize),
+    SetMember(usize, usize, MemberOption),
+    NextSeason,
+}
+
+// a saved season that can be added to a league
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SeasonOption {
+    id: u64,
+    name: String,
+    year: u16,
+}
+
+impl Display for SeasonOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.name, self.year)
+    }
+}
+
+// the member behind a team, if it has one
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemberOption(Option<String>);
+
+impl Display for MemberOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Some(member) => write!(f, "{member}"),
+            None => write!(f, "no member"),
+        }
+    }
+}
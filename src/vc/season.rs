@@ -1,30 +1,47 @@
 use super::style::container::content_title;
 use super::{CONTENT, F1_FONT, PADDING, SYMB_FONT, VCAction, VCMessage, style};
-use crate::api::Api;
+use crate::api::{Api, ScheduledRace, send_notifications, webhook_requests};
+use crate::export::{self, ExportFormat};
 use crate::fantasy_season::FantasySeason;
 use crate::fantasy_season::draft::{DraftChoice, Skip};
-use crate::fantasy_season::error::{ApiError, DownloadError};
-use crate::fantasy_season::race_results::RaceResults;
+use crate::fantasy_season::error::{ApiError, DownloadError, TeamError, WebhookError};
+use crate::fantasy_season::notification::{Notification, Webhook, WebhookFormat};
+use crate::fantasy_season::race_results::{DriverResult, RaceResults};
+use crate::fantasy_season::score::{AlternateScore, ScoreChoice};
+use crate::fantasy_season::settings::SeasonSettings;
 use iced::keyboard;
 use iced::widget::text::{danger, secondary};
 use iced::{Alignment, Element, Length, Subscription};
 use iced::{Task, widget};
+use popup::calendar::CalendarRound;
 use popup::{Popup, PopupMessage};
 use std::collections::HashMap;
-use std::time::Duration;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use time::OffsetDateTime;
 use unicode_width::UnicodeWidthStr;
 
 pub mod popup;
 
+// how often to check if the next unscored round should be polled for results
+const POLL_TICK: Duration = Duration::from_secs(30);
+// the wait after the first failed poll, which doubles after each failure up to the maximum
+const POLL_FIRST_BACKOFF: Duration = Duration::from_secs(2 * 60);
+const POLL_MAX_BACKOFF: Duration = Duration::from_secs(60 * 60);
+
 pub(super) struct Season {
     season: FantasySeason,
     current_round: u8,
-    round_names: Option<HashMap<u8, String>>,
+    schedule: Option<HashMap<u8, ScheduledRace>>,
     download_attempts: HashMap<u8, String>,
+    poll: Option<ResultsPoll>,
     popups: Vec<Popup>,
     warning: Option<String>,
+    // whether the warning is just letting the user know something worked
+    warning_is_info: bool,
     warning_count: usize,
     show_perf: bool,
+    catching_up: bool,
 }
 
 impl Season {
@@ -39,12 +56,15 @@ impl Season {
         Season {
             season,
             current_round: 1,
-            round_names: None,
+            schedule: None,
             download_attempts: HashMap::new(),
+            poll: None,
             popups: Vec::new(),
             warning: None,
+            warning_is_info: false,
             warning_count: 0,
             show_perf: true,
+            catching_up: false,
         }
     }
     pub fn view(&self) -> Element<'_, SeasonMessage> {
@@ -74,26 +94,49 @@ impl Season {
         }
     }
     fn view_top_row(&self) -> Element<'_, SeasonMessage> {
-        let round_name = if let Some(round_name) = self
-            .round_names
+        let round_name = if let Some(race) = self
+            .schedule
             .as_ref()
             .and_then(|hash| hash.get(&self.current_round))
         {
-            round_name.clone()
+            race.name.clone()
         } else {
             format!("Round {}", self.current_round)
         };
+        let round_name = if self.season.is_final_round(self.current_round) {
+            format!("{round_name} (Final Round)")
+        } else {
+            round_name
+        };
 
         crate::vc::top_row(round_name, F1_FONT, SeasonMessage::Exit)
     }
 
     fn view_status_text(&self) -> widget::Text<'_> {
         if let Some(text) = &self.warning {
-            widget::text!("{}", text).style(danger)
+            widget::text!("{}", text).style(if self.warning_is_info {
+                secondary
+            } else {
+                danger
+            })
+        } else if self.season.is_final_round(self.current_round) && self.season.is_complete() {
+            widget::text!(
+                "season complete, {} are the champions",
+                self.season.get_champion().unwrap_or_default()
+            )
+            .style(secondary)
         } else {
             widget::text!(
                 "{}",
                 match self.season.get_status_at(self.current_round) {
+                    (_, true, _)
+                        if self
+                            .season
+                            .get_results_at(self.current_round)
+                            .is_some_and(|r| r.is_manual()) =>
+                    {
+                        "round results entered by hand"
+                    }
                     (_, true, _) => "round results downloaded",
                     (_, false, _) => match self.download_attempts.get(&self.current_round) {
                         Some(msg) => msg,
@@ -154,7 +197,7 @@ impl Season {
                 data_col,
             )
         } else {
-            let mut teams = self.season.get_team_names();
+            let mut teams = self.season.get_drafting_team_names(self.current_round);
             teams.sort();
             let size = teams.len();
             Self::view_table_teams(
@@ -221,14 +264,17 @@ impl Season {
         .align_x(Alignment::Center)
     }
 
-    fn view_bottom_row(&self) -> widget::Row<'_, SeasonMessage> {
+    // the round's actions between the arrows, with the season's views and tools on a row above
+    fn view_bottom_row(&self) -> widget::Column<'_, SeasonMessage> {
         let prev_status = if self.current_round == 1 {
             (true, true, true)
         } else {
             self.season.get_status_at(self.current_round - 1)
         };
         let status = self.season.get_status_at(self.current_round);
-        let next_status = self.season.get_status_at(self.current_round + 1);
+        let next_status = self
+            .season
+            .get_status_at(self.current_round.saturating_add(1));
 
         let add_button = match (prev_status, status) {
             ((false, _, _), _) => widget::button("draft"),
@@ -266,26 +312,89 @@ impl Season {
             .on_press(SeasonMessage::ToggleDriverPerf)
             .style(style::button::secondary);
 
+        let catch_up_button = widget::button("catch up")
+            .on_press_maybe((!self.catching_up).then_some(SeasonMessage::CatchUp))
+            .style(style::button::secondary);
+
+        let calendar_button = widget::button("calendar")
+            .on_press(SeasonMessage::OpenCalendar)
+            .style(style::button::secondary);
+
+        let chart_button = widget::button("chart")
+            .on_press(SeasonMessage::OpenChart)
+            .style(style::button::secondary);
+
+        let score_mode_button = widget::button("score mode")
+            .on_press(SeasonMessage::OpenScoreMode)
+            .style(style::button::secondary);
+
+        let teams_button = widget::button("teams")
+            .on_press(SeasonMessage::OpenTeams)
+            .style(style::button::secondary);
+
+        let settings_button = widget::button("settings")
+            .on_press(SeasonMessage::OpenSettings)
+            .style(style::button::secondary);
+
+        let what_if_button = widget::button("what if")
+            .on_press(SeasonMessage::OpenWhatIf)
+            .style(style::button::secondary);
+
+        let export_button = widget::button("export")
+            .on_press(SeasonMessage::OpenExport)
+            .style(style::button::secondary);
+
+        let notifications_button = widget::button("notifications")
+            .on_press(SeasonMessage::OpenNotifications)
+            .style(style::button::secondary);
+
+        let results_button = widget::button("edit results")
+            .on_press_maybe((!self.catching_up).then_some(SeasonMessage::OpenResults))
+            .style(style::button::secondary);
+
+        let history_button = widget::button("history")
+            .on_press(SeasonMessage::OpenHistory)
+            .style(style::button::secondary);
+
         let left_button = widget::button(widget::text!("\u{e5c4}").font(SYMB_FONT))
             .style(widget::button::text)
             .on_press_maybe((!self.current_round.eq(&1)).then_some(SeasonMessage::DecrementRound));
 
         let right_button = widget::button(widget::text!("\u{e5c8}").font(SYMB_FONT))
             .style(widget::button::text)
-            .on_press(SeasonMessage::IncrementRound);
+            .on_press_maybe((!self.is_last_round()).then_some(SeasonMessage::IncrementRound));
 
-        widget::row![
+        let tools_row = widget::row![
+            widget::horizontal_space(),
+            driver_perf_button,
+            calendar_button,
+            chart_button,
+            what_if_button,
+            score_mode_button,
+            teams_button,
+            settings_button,
+            export_button,
+            notifications_button,
+            history_button,
+            widget::horizontal_space(),
+        ]
+        .spacing(PADDING);
+
+        let round_row = widget::row![
             left_button,
             widget::horizontal_space(),
             add_button,
             edit_lineup_button,
             delete_lineup_button,
             delete_round_button,
-            driver_perf_button,
+            results_button,
+            catch_up_button,
             widget::horizontal_space(),
             right_button,
         ]
-        .spacing(PADDING)
+        .spacing(PADDING);
+
+        widget::column![tools_row, round_row].spacing(PADDING)
     }
 
     fn view_table<'a>(
@@ -313,14 +422,16 @@ impl Season {
     ) -> Element<'a, SeasonMessage> {
         let table_width = title.width() + 2;
         let data_width_max = data.iter().map(|x| x.width()).max().unwrap_or_default();
-        let title_width = (table_width - data_width_max).max(0);
+        let title_width = table_width.saturating_sub(data_width_max);
 
         let content = widget::row![
-            widget::Column::from_iter(
-                teams
-                    .into_iter()
-                    .map(|x| widget::text!("{x:title_width$}").size(CONTENT).into())
-            ),
+            widget::Column::from_iter(teams.into_iter().map(|x| {
+                widget::button(widget::text!("{x:title_width$}").size(CONTENT))
+                    .padding(0)
+                    .style(widget::button::text)
+                    .on_press(SeasonMessage::OpenTeam(x))
+                    .into()
+            })),
             widget::Column::from_iter(
                 data.into_iter()
                     .map(|x| widget::text!("{x}").size(CONTENT).into())
@@ -350,7 +461,13 @@ impl Season {
 
             for _ in 0..(std::cmp::min(rows, driver_perf.len())) {
                 let driver = driver_perf.remove(0);
-                driver_col.push(widget::text!("{:02}:", driver.0).size(CONTENT).into());
+                driver_col.push(
+                    widget::button(widget::text!("{:02}:", driver.0).size(CONTENT))
+                        .padding(0)
+                        .style(widget::button::text)
+                        .on_press(SeasonMessage::OpenDriver(driver.0))
+                        .into(),
+                );
                 points_col.push(widget::text!("{}", driver.1).size(CONTENT).into());
             }
 
@@ -379,8 +496,10 @@ impl Season {
                 return self.handle_action(action);
             }
             SeasonMessage::IncrementRound => {
-                self.current_round += 1;
-                return VCAction::Task(self.download_task().map(VCMessage::Season));
+                if !self.is_last_round() {
+                    self.current_round += 1;
+                    return VCAction::Task(self.download_task().map(VCMessage::Season));
+                }
             }
             SeasonMessage::DecrementRound => {
                 if self.current_round > 1 {
@@ -392,37 +511,54 @@ impl Season {
                 return VCAction::Task(self.download_task().map(VCMessage::Season));
             }
             SeasonMessage::DraftStart => match self.season.get_draft_choice() {
+                _ if !self.season.needs_drafter(self.current_round) => {
+                    if let Err(de) = self.season.draft(self.current_round, &mut Skip::new()) {
+                        return self.warn(de.to_string());
+                    }
+                }
                 DraftChoice::Skip => {
-                    self.season
-                        .draft(self.current_round, &mut Skip::new())
-                        .expect("TODO");
+                    // skip seasons only need a drafter once the lineup has grown, so the previous
+                    // lineups are kept with empty spaces for the extra drivers
+                    let size = self.season.get_lineup_size_at(self.current_round) as usize;
+                    let lineups = self
+                        .previous_lineups()
+                        .into_iter()
+                        .map(|(team, lineup)| {
+                            let mut drivers: Vec<_> =
+                                lineup.iter().map(|d| d.to_string()).collect();
+                            drivers.resize(size, String::new());
+                            (team, drivers)
+                        })
+                        .collect();
+                    self.popups.push(Popup::replace_all_from(
+                        lineups,
+                        self.season.enforces_unique(),
+                    ));
                 }
                 DraftChoice::RollOn => {
                     self.popups.push(Popup::new_roll_on(
-                        self.season.get_lineup_at(self.current_round - 1),
+                        self.previous_lineups(),
+                        self.season.get_roll_on_releases(self.current_round),
+                        self.season.get_lineup_size_at(self.current_round) as usize,
                         self.season.enforces_unique(),
                     ));
                 }
                 DraftChoice::ReplaceAll => {
                     self.popups.push(Popup::new_replace_all(
-                        self.season.get_team_names(),
-                        self.season.get_lineup_size() as usize,
+                        self.season.get_drafting_team_names(self.current_round),
+                        self.season.get_lineup_size_at(self.current_round) as usize,
                         self.season.enforces_unique(),
                     ));
                 }
             },
             SeasonMessage::Score => {
                 if let Err(se) = self.season.score(self.current_round) {
-                    self.warning = Some(se.to_string());
-                    self.warning_count += 1;
-                    return VCAction::Task(
-                        Task::perform(
-                            async { tokio::time::sleep(Duration::from_secs(5)).await },
-                            |_| SeasonMessage::RemoveWarning,
-                        )
-                        .map(VCMessage::Season),
-                    );
+                    return self.warn(se.to_string());
                 }
+                if self.season.is_complete() {
+                    self.popups.push(self.season_complete_popup());
+                }
+                return self.notify(vec![Notification::RoundScored(self.current_round)]);
             }
             SeasonMessage::ReplaceLineup => {
                 let team_lineups = self
@@ -437,13 +573,29 @@ impl Season {
                 ));
             }
             SeasonMessage::DownloadedResults(result) => {
+                // a poll can finish after catching up has already downloaded the same round
+                if self.season.get_status_at(result.0).1 {
+                    return VCAction::None;
+                }
                 if let Ok(rr) = result.1 {
                     self.season
                         .update_results(result.0, rr)
                         .expect("cannot happen");
                     self.download_attempts.remove(&result.0);
+                    return self.notify(vec![Notification::ResultsAvailable(result.0)]);
                 } else if let Err(err) = result.1 {
-                    self.download_attempts.insert(result.0, err.to_string());
+                    let message = match &self.poll {
+                        Some(poll) if poll.round == result.0 => format!(
+                            "{err}, checking again in {} minutes",
+                            poll.next_check
+                                .saturating_duration_since(Instant::now())
+                                .as_secs()
+                                .div_ceil(60)
+                                .max(1)
+                        ),
+                        _ => err.to_string(),
+                    };
+                    self.download_attempts.insert(result.0, message);
                 }
             }
             SeasonMessage::DeleteLineup => {
@@ -457,14 +609,54 @@ impl Season {
                 );
                 self.download_attempts.remove(&self.current_round);
             }
-            SeasonMessage::DownloadedRaceNames(results) => {
-                self.round_names = results.ok();
+            SeasonMessage::DownloadedSchedule(results) => {
+                self.schedule = results.ok();
+                if let Some(round_count) = self
+                    .schedule
+                    .as_ref()
+                    .and_then(|schedule| schedule.keys().max())
+                {
+                    self.season.set_round_count(*round_count);
+                    self.current_round = self.current_round.min(*round_count);
+                }
             }
-            SeasonMessage::DownloadRaceNames => {
+            SeasonMessage::DownloadSchedule => {
                 return VCAction::Task(
                     Task::perform(
-                        download_race_names(self.season.get_season()),
-                        SeasonMessage::DownloadedRaceNames,
+                        download_schedule(self.season.get_season()),
+                        SeasonMessage::DownloadedSchedule,
+                    )
+                    .map(VCMessage::Season),
+                );
+            }
+            SeasonMessage::PollResults => {
+                let Some(round) = self.poll_round() else {
+                    self.poll = None;
+                    return VCAction::None;
+                };
+                let now = Instant::now();
+                let poll = match &mut self.poll {
+                    Some(poll) if poll.round == round => poll,
+                    poll => poll.insert(ResultsPoll {
+                        round,
+                        next_check: now,
+                        backoff: POLL_FIRST_BACKOFF,
+                    }),
+                };
+                if now < poll.next_check {
+                    return VCAction::None;
+                }
+
+                // the backoff is applied now rather than on failure, so a slow download is not
+                // started twice
+                poll.next_check = now + poll.backoff;
+                poll.backoff = (poll.backoff * 2).min(POLL_MAX_BACKOFF);
+                self.download_attempts
+                    .insert(round, "round results downloading".to_string());
+                return VCAction::Task(
+                    Task::perform(
+                        build_with_round(round, self.season.get_season()),
+                        SeasonMessage::DownloadedResults,
                     )
                     .map(VCMessage::Season),
                 );
@@ -476,6 +668,132 @@ impl Season {
                 }
             }
             SeasonMessage::ToggleDriverPerf => self.show_perf = !self.show_perf,
+            SeasonMessage::CatchUp => {
+                self.catching_up = true;
+                return VCAction::Task(
+                    Task::perform(
+                        RaceResults::build_until_unavailable(
+                            self.season.get_rounds_without_results(),
+                            self.season.get_season(),
+                        ),
+                        SeasonMessage::CaughtUpResults,
+                    )
+                    .map(VCMessage::Season),
+                );
+            }
+            SeasonMessage::CaughtUpResults(results) => {
+                self.catching_up = false;
+                let mut notifications = Vec::new();
+                for (round, result) in results {
+                    match result {
+                        // a poll may have downloaded the round while catching up
+                        Ok(_) if self.season.get_status_at(round).1 => {}
+                        Ok(rr) => {
+                            self.season
+                                .update_results(round, rr)
+                                .expect("cannot happen");
+                            self.download_attempts.remove(&round);
+                            notifications.push(Notification::ResultsAvailable(round));
+                        }
+                        Err(err) => {
+                            self.download_attempts.insert(round, err.to_string());
+                        }
+                    }
+                }
+
+                let report = self.season.catch_up();
+                if let Some(round) = report.stopped_at() {
+                    self.current_round = round;
+                }
+                if self.season.is_complete() && !report.scored.is_empty() {
+                    self.popups.push(self.season_complete_popup());
+                }
+                self.popups
+                    .push(Popup::new_report("catch up", report.summary()));
+                notifications.extend(report.scored.iter().map(|r| Notification::RoundScored(*r)));
+                return self.notify(notifications);
+            }
+            SeasonMessage::OpenCalendar => {
+                self.popups.push(Popup::new_calendar(
+                    self.calendar_rounds(),
+                    self.current_round,
+                ));
+            }
+            SeasonMessage::OpenChart => {
+                self.popups
+                    .push(Popup::new_chart(self.season.get_standings_progression()));
+            }
+            SeasonMessage::OpenTeam(team) => {
+                if let Some(report) = self.season.get_team_report(&team) {
+                    self.popups.push(Popup::new_team(report));
+                }
+            }
+            SeasonMessage::OpenDriver(driver) => {
+                self.popups
+                    .push(Popup::new_driver(self.season.get_driver_report(driver)));
+            }
+            SeasonMessage::OpenWhatIf => {
+                self.popups.push(Popup::new_what_if(
+                    self.current_round,
+                    self.season.get_score_choice_at(self.current_round),
+                    self.season.get_points_by(self.current_round),
+                ));
+            }
+            SeasonMessage::OpenScoreMode => {
+                self.popups.push(Popup::new_score_mode(
+                    self.current_round,
+                    self.season.get_score_choice_at(self.current_round),
+                    self.season.get_score_changes().to_vec(),
+                ));
+            }
+            SeasonMessage::OpenTeams => {
+                self.popups.push(self.teams_popup());
+            }
+            SeasonMessage::OpenSettings => {
+                self.popups.push(Popup::new_settings(
+                    self.current_round,
+                    self.season.get_settings(self.current_round),
+                ));
+            }
+            SeasonMessage::OpenExport => {
+                self.popups.push(Popup::new_export(
+                    self.current_round,
+                    self.season.get_name(),
+                ));
+            }
+            SeasonMessage::OpenResults => {
+                let results = self
+                    .season
+                    .get_results_at(self.current_round)
+                    .map(|r| r.get_drivers())
+                    .unwrap_or_default();
+                self.popups.push(Popup::new_results(
+                    self.current_round,
+                    results,
+                    self.season.get_settings(self.current_round).grid_size,
+                ));
+            }
+            SeasonMessage::OpenNotifications => {
+                self.popups.push(Popup::new_notifications(
+                    self.season.get_webhooks().to_vec(),
+                ));
+            }
+            SeasonMessage::Notified(notification, result) => {
+                // a test was asked for from the notifications popup, so its result is shown there
+                if notification == Notification::Test {
+                    if let Some(popup) = self.popups.last_mut() {
+                        popup.set_test_result(result.map_err(|e| e.to_string()));
+                    }
+                } else if let Err(err) = result {
+                    return self.warn(format!("notifications could not be sent: {err}"));
+                }
+            }
+            SeasonMessage::OpenHistory => {
+                self.popups.push(Popup::new_history(
+                    self.season.get_history(),
+                    self.current_round,
+                ));
+            }
             SeasonMessage::Exit => {
                 if !self.popups.is_empty() {
                     self.popups.pop();
@@ -496,12 +814,108 @@ impl Season {
                     .pop()
                     .expect("IMPOSSIBLE: PM CAN ONLY TRIGGER WHEN THERE IS A POPUP")
                     .get_drafter();
-                self.season
-                    .delete_lineup(self.current_round)
-                    .expect("IMPOSSIBLE: UI PREVENTS FROM BEING TRIGGERED WHEN METHOD WOULD ERROR");
-                self.season
-                    .draft(self.current_round, &mut *drafter)
-                    .expect("IMPOSSIBLE: UI CANNOT CREATE AN INVALID DRAFTER");
+                // teams that joined this round are not in the drafter, so their lineup can still
+                // clash with the drafted ones
+                if let Err(de) = self
+                    .season
+                    .replace_lineup(self.current_round, &mut *drafter)
+                {
+                    return self.warn(de.to_string());
+                }
+            }
+            SeasonAction::GoToRound(round) => {
+                self.popups.pop();
+                self.current_round = round;
+                return VCAction::Task(self.download_task().map(VCMessage::Season));
+            }
+            SeasonAction::WhatIf(alternate) => {
+                let points = self
+                    .season
+                    .get_what_if_points_by(self.current_round, &alternate);
+                self.popups
+                    .last_mut()
+                    .expect("IMPOSSIBLE: PM CAN ONLY TRIGGER WHEN THERE IS A POPUP")
+                    .set_what_if(alternate, points);
+            }
+            SeasonAction::ChangeScoreChoice(choice, retroactive) => {
+                self.popups
+                    .pop()
+                    .expect("IMPOSSIBLE: PM CAN ONLY TRIGGER WHEN THERE IS A POPUP");
+                if let Err(se) =
+                    self.season
+                        .change_score_choice(self.current_round, choice, retroactive)
+                {
+                    return self.warn(se.to_string());
+                }
+            }
+            SeasonAction::ChangeSettings(settings) => {
+                // the popup stays open on an error so the settings can be fixed
+                match self.season.change_settings(self.current_round, settings) {
+                    Ok(()) => {
+                        self.popups.pop();
+                    }
+                    Err(se) => self
+                        .popups
+                        .last_mut()
+                        .expect("IMPOSSIBLE: PM CAN ONLY TRIGGER WHEN THERE IS A POPUP")
+                        .set_error(se.to_string()),
+                }
+            }
+            SeasonAction::Export(format, path) => {
+                let report = self.season.get_round_report(self.current_round);
+                match export::write(&path, &report, format) {
+                    Ok(()) => {
+                        self.popups.pop();
+                        return self.inform(format!(
+                            "exported round {} to {}",
+                            self.current_round,
+                            path.display()
+                        ));
+                    }
+                    Err(e) => self
+                        .popups
+                        .last_mut()
+                        .expect("IMPOSSIBLE: PM CAN ONLY TRIGGER WHEN THERE IS A POPUP")
+                        .set_error(e.to_string()),
+                }
+            }
+            SeasonAction::AddTeam(name, lineup, handicap) => {
+                let result = self
+                    .season
+                    .add_team(name, self.current_round, lineup, handicap);
+                self.refresh_teams_popup(result);
+            }
+            SeasonAction::RetireTeam(name) => {
+                let result = self.season.retire_team(&name, self.current_round);
+                self.refresh_teams_popup(result);
+            }
+            SeasonAction::AddWebhook(url, format) => {
+                let result = Webhook::new(url, format).and_then(|w| self.season.add_webhook(w));
+                self.refresh_notifications_popup(result);
+            }
+            SeasonAction::RemoveWebhook(idx) => {
+                self.season.remove_webhook(idx);
+                self.refresh_notifications_popup(Ok(()));
+            }
+            SeasonAction::TestWebhooks => return self.notify(vec![Notification::Test]),
+            SeasonAction::SetResults(results) => {
+                // the popup stays open on an error so the results can be fixed
+                let downloaded = self.season.get_status_at(self.current_round).1;
+                match self.season.set_manual_results(self.current_round, results) {
+                    Ok(()) => {
+                        self.popups.pop();
+                        self.download_attempts.remove(&self.current_round);
+                        if !downloaded {
+                            return self
+                                .notify(vec![Notification::ResultsAvailable(self.current_round)]);
+                        }
+                    }
+                    Err(re) => self
+                        .popups
+                        .last_mut()
+                        .expect("IMPOSSIBLE: PM CAN ONLY TRIGGER WHEN THERE IS A POPUP")
+                        .set_error(re.to_string()),
+                }
             }
             SeasonAction::ClosePopup => {
                 self.popups
@@ -514,6 +928,159 @@ impl Season {
         VCAction::None
     }
 
+    // the lineups from the previous round of the teams still to be drafted this round
+    fn previous_lineups(&self) -> HashMap<String, Vec<u8>> {
+        let drafting = self.season.get_drafting_team_names(self.current_round);
+        let mut previous_lineup = self.season.get_lineup_at(self.current_round - 1);
+        previous_lineup.retain(|team, _| drafting.contains(team));
+        previous_lineup
+    }
+
+    fn teams_popup(&self) -> Popup {
+        Popup::new_teams(
+            self.current_round,
+            self.season.get_team_memberships(),
+            self.season.get_lineup_size_at(self.current_round) as usize,
+        )
+    }
+
+    // replaces the teams popup so it shows the change, or shows why the change failed
+    fn refresh_teams_popup(&mut self, result: Result<(), TeamError>) {
+        let refreshed = self.teams_popup();
+        let popup = self
+            .popups
+            .last_mut()
+            .expect("IMPOSSIBLE: PM CAN ONLY TRIGGER WHEN THERE IS A POPUP");
+        match result {
+            Ok(()) => *popup = refreshed,
+            Err(te) => popup.set_error(te.to_string()),
+        }
+    }
+
+    // replaces the notifications popup so it shows the change, or shows why the change failed
+    fn refresh_notifications_popup(&mut self, result: Result<(), WebhookError>) {
+        let refreshed = Popup::new_notifications(self.season.get_webhooks().to_vec());
+        let popup = self
+            .popups
+            .last_mut()
+            .expect("IMPOSSIBLE: PM CAN ONLY TRIGGER WHEN THERE IS A POPUP");
+        match result {
+            Ok(()) => *popup = refreshed,
+            Err(we) => popup.set_error(we.to_string()),
+        }
+    }
+
+    // sends the season's notifications to its webhooks in the background
+    fn notify(&self, notifications: Vec<Notification>) -> VCAction {
+        if self.season.get_webhooks().is_empty() {
+            return VCAction::None;
+        }
+        let tasks: Vec<_> = notifications
+            .into_iter()
+            .filter_map(|notification| {
+                let requests = webhook_requests(
+                    self.season.get_webhooks(),
+                    &self.season.get_notification_message(notification),
+                );
+                (!requests.is_empty()).then(|| {
+                    Task::perform(send_notifications(requests), move |result| {
+                        SeasonMessage::Notified(notification, result)
+                    })
+                })
+            })
+            .collect();
+
+        if tasks.is_empty() {
+            VCAction::None
+        } else {
+            VCAction::Task(Task::batch(tasks).map(VCMessage::Season))
+        }
+    }
+
+    // shows a warning for a few seconds
+    fn warn(&mut self, warning: String) -> VCAction {
+        self.show_status(warning, false)
+    }
+
+    // shows a message that something worked for a few seconds
+    fn inform(&mut self, message: String) -> VCAction {
+        self.show_status(message, true)
+    }
+
+    fn show_status(&mut self, text: String, info: bool) -> VCAction {
+        self.warning = Some(text);
+        self.warning_is_info = info;
+        self.warning_count += 1;
+        VCAction::Task(
+            Task::perform(
+                async { tokio::time::sleep(Duration::from_secs(5)).await },
+                |_| SeasonMessage::RemoveWarning,
+            )
+            .map(VCMessage::Season),
+        )
+    }
+
+    // the last round that can be navigated to, either the final round of the season or the last
+    // round a u8 can hold
+    fn is_last_round(&self) -> bool {
+        self.season.is_final_round(self.current_round) || self.current_round == u8::MAX
+    }
+
+    fn season_complete_popup(&self) -> Popup {
+        let standings = self.season.get_points_by(u8::MAX);
+        let width = standings
+            .iter()
+            .map(|(team, _)| team.width())
+            .max()
+            .unwrap_or_default();
+
+        let mut lines = vec![
+            format!(
+                "{} won {}",
+                self.season.get_champion().unwrap_or_default(),
+                self.season.get_name()
+            ),
+            String::from("final standings:"),
+        ];
+        lines.extend(
+            standings
+                .into_iter()
+                .enumerate()
+                .map(|(pos, (team, points))| format!("{:>2}. {team:width$} {points:>5}", pos + 1)),
+        );
+
+        Popup::new_report("season complete", lines)
+    }
+
+    // every round in the schedule, or if it has not been downloaded, every round up to the
+    // last one that has been started
+    fn calendar_rounds(&self) -> Vec<CalendarRound> {
+        let mut rounds: Vec<u8> = match &self.schedule {
+            Some(schedule) => schedule.keys().copied().collect(),
+            None => {
+                let last_started = (1..=u8::MAX)
+                    .rev()
+                    .find(|r| self.season.get_status_at(*r) != (false, false, false))
+                    .unwrap_or(1);
+                (1..=last_started.max(self.current_round)).collect()
+            }
+        };
+        rounds.sort();
+
+        rounds
+            .into_iter()
+            .map(|round| CalendarRound {
+                round,
+                race: self
+                    .schedule
+                    .as_ref()
+                    .and_then(|schedule| schedule.get(&round))
+                    .cloned(),
+                status: self.season.get_status_at(round),
+            })
+            .collect()
+    }
+
     fn download_task(&mut self) -> Task<SeasonMessage> {
         if !self.season.get_status_at(self.current_round).1
             && !self.download_attempts.contains_key(&self.current_round)
@@ -529,6 +1096,17 @@ impl Season {
         }
     }
 
+    // the first round without results, once its race has taken place. Without a schedule there is
+    // no race date, so nothing is polled
+    fn poll_round(&self) -> Option<u8> {
+        if self.catching_up {
+            return None;
+        }
+        let round = *self.season.get_rounds_without_results().first()?;
+        let race = self.schedule.as_ref()?.get(&round)?;
+        (race.date <= OffsetDateTime::now_utc().date()).then_some(round)
+    }
+
     pub fn subscription(&self) -> Subscription<SeasonMessage> {
         fn handle_keystroke(
             key: keyboard::Key,
@@ -546,17 +1124,32 @@ impl Season {
             }
         }
 
-        keyboard::on_key_press(handle_keystroke)
+        let keys = keyboard::on_key_press(handle_keystroke);
+        if self.poll_round().is_some() {
+            Subscription::batch(vec![
+                keys,
+                iced::time::every(POLL_TICK).map(|_| SeasonMessage::PollResults),
+            ])
+        } else {
+            keys
+        }
     }
 }
 
+// polling for the results of a round whose race has taken place
+struct ResultsPoll {
+    round: u8,
+    next_check: Instant,
+    backoff: Duration,
+}
+
 async fn build_with_round(round: u8, season: u16) -> (u8, Result<RaceResults, DownloadError>) {
     (round, RaceResults::build(round, season).await)
 }
 
-async fn download_race_names(season: u16) -> Result<HashMap<u8, String>, ApiError> {
+async fn download_schedule(season: u16) -> Result<HashMap<u8, ScheduledRace>, ApiError> {
     let api = Api::new();
-    api.get_race_names(season).await
+    api.get_schedule(season).await
 }
 
 #[derive(Debug, Clone)]
@@ -571,15 +1164,42 @@ pub enum SeasonMessage {
     DownloadedResults((u8, Result<RaceResults, DownloadError>)),
     DeleteLineup,
     DeleteRound,
-    DownloadRaceNames,
-    DownloadedRaceNames(Result<HashMap<u8, String>, ApiError>),
+    DownloadSchedule,
+    PollResults,
+    DownloadedSchedule(Result<HashMap<u8, ScheduledRace>, ApiError>),
     RemoveWarning,
     ToggleDriverPerf,
+    CatchUp,
+    CaughtUpResults(Vec<(u8, Result<RaceResults, DownloadError>)>),
+    OpenCalendar,
+    OpenChart,
+    OpenTeam(String),
+    OpenDriver(u8),
+    OpenWhatIf,
+    OpenScoreMode,
+    OpenTeams,
+    OpenSettings,
+    OpenExport,
+    OpenNotifications,
+    OpenResults,
+    Notified(Notification, Result<(), ApiError>),
+    OpenHistory,
     Exit,
 }
 
 pub enum SeasonAction {
     UpdateLineup,
+    GoToRound(u8),
+    WhatIf(AlternateScore),
+    ChangeScoreChoice(ScoreChoice, bool),
+    ChangeSettings(SeasonSettings),
+    AddTeam(String, Vec<u8>, i16),
+    RetireTeam(String),
+    Export(ExportFormat, PathBuf),
+    AddWebhook(String, WebhookFormat),
+    RemoveWebhook(usize),
+    TestWebhooks,
+    SetResults(Vec<(u8, DriverResult)>),
     ClosePopup,
     None,
 }
@@ -1,16 +1,51 @@
+use crate::export::ExportFormat;
+use crate::fantasy_season::Standing;
 use crate::fantasy_season::draft::Drafter;
+use crate::fantasy_season::history::HistoryEntry;
+use crate::fantasy_season::notification::{Webhook, WebhookFormat};
+use crate::fantasy_season::race_results::DriverResult;
+use crate::fantasy_season::report::{DriverReport, TeamReport};
+use crate::fantasy_season::score::{AlternateScore, ScoreChange, ScoreChoice};
+use crate::fantasy_season::settings::SeasonSettings;
 use crate::vc::MONO_FONT;
 use crate::vc::season::SeasonAction;
 use crate::vc::{CONTENT, PADDING};
 use crate::vc::{CONTENT_INPUT_PADDED, style};
+use calendar::{Calendar, CalendarRound};
+use chart::Chart;
+use driver::DriverDetail;
+use export::Export;
+use history::History;
 use iced::{Alignment, Element, Length, widget};
+use notifications::Notifications;
 use replace_all_drafter::ReplaceAllDrafter;
+use report::Report;
+use results::ResultsEditor;
 use roll_on_drafter::RollOnDrafter;
+use score_mode::ScoreMode;
+use settings::Settings;
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::path::PathBuf;
+use team::TeamDetail;
+use teams::Teams;
+use what_if::WhatIf;
 
+pub mod calendar;
+pub mod chart;
+pub mod driver;
+pub mod export;
+pub mod history;
+pub mod notifications;
 pub mod replace_all_drafter;
+pub mod report;
+pub mod results;
 pub mod roll_on_drafter;
+pub mod score_mode;
+pub mod settings;
+pub mod team;
+pub mod teams;
+pub mod what_if;
 
 pub(super) struct Popup {
     title: String,
@@ -20,28 +55,69 @@ pub(super) struct Popup {
 enum PopupKind {
     RollOnDrafter(RollOnDrafter),
     ReplaceAllDrafter(ReplaceAllDrafter),
+    History(History),
+    Report(Report),
+    Calendar(Calendar),
+    Chart(Chart),
+    Team(TeamDetail),
+    Driver(DriverDetail),
+    WhatIf(WhatIf),
+    ScoreMode(ScoreMode),
+    Settings(Settings),
+    Teams(Teams),
+    Export(Export),
+    Notifications(Notifications),
+    Results(ResultsEditor),
 }
 
 #[derive(Clone, Debug)]
 pub enum PopupMessage {
     RollOn(roll_on_drafter::ROMessage),
     ReplaceAll(replace_all_drafter::RAMessage),
+    History(history::HMessage),
+    Calendar(calendar::CMessage),
+    Chart(chart::ChartMessage),
+    WhatIf(what_if::WIMessage),
+    ScoreMode(score_mode::SMMessage),
+    Settings(settings::SetMessage),
+    Teams(teams::TMessage),
+    Export(export::ExMessage),
+    Notifications(notifications::NMessage),
+    Results(results::REMessage),
     Close,
 }
 
 pub enum PopupAction {
     UpdateLineup,
+    GoToRound(u8),
+    WhatIf(AlternateScore),
+    ChangeScoreChoice(ScoreChoice, bool),
+    ChangeSettings(SeasonSettings),
+    AddTeam(String, Vec<u8>, i16),
+    RetireTeam(String),
+    Export(ExportFormat, PathBuf),
+    AddWebhook(String, WebhookFormat),
+    RemoveWebhook(usize),
+    TestWebhooks,
+    SetResults(Vec<(u8, DriverResult)>),
     None,
 }
 
 impl Popup {
     pub fn new_roll_on(
         previous_lineup: HashMap<String, Vec<u8>>,
+        releases: Option<HashMap<String, Vec<u8>>>,
+        lineup_size: usize,
         enforce_uniqueness: bool,
     ) -> Popup {
         Popup {
             title: "draft new drivers".to_string(),
-            kind: PopupKind::RollOnDrafter(RollOnDrafter::new(previous_lineup, enforce_uniqueness)),
+            kind: PopupKind::RollOnDrafter(RollOnDrafter::new(
+                previous_lineup,
+                releases,
+                lineup_size,
+                enforce_uniqueness,
+            )),
         }
     }
 
@@ -73,10 +149,146 @@ impl Popup {
         }
     }
 
+    pub fn new_history(entries: Vec<HistoryEntry>, round: u8) -> Popup {
+        Popup {
+            title: "season history".to_string(),
+            kind: PopupKind::History(History::new(entries, round)),
+        }
+    }
+
+    pub fn new_report(title: &str, lines: Vec<String>) -> Popup {
+        Popup {
+            title: title.to_string(),
+            kind: PopupKind::Report(Report::new(lines)),
+        }
+    }
+
+    pub fn new_calendar(rounds: Vec<CalendarRound>, current_round: u8) -> Popup {
+        Popup {
+            title: "season calendar".to_string(),
+            kind: PopupKind::Calendar(Calendar::new(rounds, current_round)),
+        }
+    }
+
+    pub fn new_chart(progression: Vec<(String, Vec<Standing>)>) -> Popup {
+        Popup {
+            title: "standings progression".to_string(),
+            kind: PopupKind::Chart(Chart::new(progression)),
+        }
+    }
+
+    pub fn new_team(report: TeamReport) -> Popup {
+        Popup {
+            title: report.name.clone(),
+            kind: PopupKind::Team(TeamDetail::new(report)),
+        }
+    }
+
+    pub fn new_driver(report: DriverReport) -> Popup {
+        Popup {
+            title: format!("driver #{:02}", report.driver),
+            kind: PopupKind::Driver(DriverDetail::new(report)),
+        }
+    }
+
+    pub fn new_what_if(round: u8, actual_choice: ScoreChoice, actual: Vec<(String, i16)>) -> Popup {
+        Popup {
+            title: "what if".to_string(),
+            kind: PopupKind::WhatIf(WhatIf::new(round, actual_choice, actual)),
+        }
+    }
+
+    pub fn new_score_mode(round: u8, current: ScoreChoice, changes: Vec<ScoreChange>) -> Popup {
+        Popup {
+            title: "score mode".to_string(),
+            kind: PopupKind::ScoreMode(ScoreMode::new(round, current, changes)),
+        }
+    }
+
+    pub fn new_settings(round: u8, settings: SeasonSettings) -> Popup {
+        Popup {
+            title: "season settings".to_string(),
+            kind: PopupKind::Settings(Settings::new(round, settings)),
+        }
+    }
+
+    pub fn new_export(round: u8, season_name: &str) -> Popup {
+        Popup {
+            title: "export round".to_string(),
+            kind: PopupKind::Export(Export::new(round, season_name)),
+        }
+    }
+
+    pub fn new_notifications(webhooks: Vec<Webhook>) -> Popup {
+        Popup {
+            title: "notifications".to_string(),
+            kind: PopupKind::Notifications(Notifications::new(webhooks)),
+        }
+    }
+
+    pub fn new_results(round: u8, results: Vec<(u8, DriverResult)>, grid_size: u8) -> Popup {
+        Popup {
+            title: format!("round {round} results"),
+            kind: PopupKind::Results(ResultsEditor::new(results, grid_size)),
+        }
+    }
+
+    pub fn new_teams(
+        round: u8,
+        memberships: Vec<(String, u8, Option<u8>)>,
+        lineup_size: usize,
+    ) -> Popup {
+        Popup {
+            title: "teams".to_string(),
+            kind: PopupKind::Teams(Teams::new(round, memberships, lineup_size)),
+        }
+    }
+
+    // shows why the changes made in the popup could not be applied
+    pub fn set_error(&mut self, error: String) {
+        match &mut self.kind {
+            PopupKind::Settings(set) => set.set_error(error),
+            PopupKind::Teams(t) => t.set_error(error),
+            PopupKind::Export(ex) => ex.set_error(error),
+            PopupKind::Notifications(n) => n.set_error(error),
+            PopupKind::Results(r) => r.set_error(error),
+            _ => panic!("IMPOSSIBLE: ONLY EDITING POPUPS CAN SHOW AN ERROR"),
+        }
+    }
+
+    pub fn set_what_if(&mut self, alternate: AlternateScore, points: Vec<(String, i16)>) {
+        match &mut self.kind {
+            PopupKind::WhatIf(w) => w.set_results(alternate, points),
+            _ => panic!("IMPOSSIBLE: ONLY WHAT IF POPUPS CAN SHOW RESCORED STANDINGS"),
+        }
+    }
+
+    // the popup may have been closed while the test was being sent, so other popups ignore it
+    pub fn set_test_result(&mut self, result: Result<(), String>) {
+        if let PopupKind::Notifications(n) = &mut self.kind {
+            n.set_test_result(result);
+        }
+    }
+
     pub fn get_drafter(self) -> Box<dyn Drafter> {
         match self.kind {
             PopupKind::RollOnDrafter(ro) => Box::new(ro.get_drafter()),
             PopupKind::ReplaceAllDrafter(ra) => Box::new(ra.get_drafter()),
+            PopupKind::History(_)
+            | PopupKind::Report(_)
+            | PopupKind::Calendar(_)
+            | PopupKind::Chart(_)
+            | PopupKind::Team(_)
+            | PopupKind::Driver(_)
+            | PopupKind::WhatIf(_)
+            | PopupKind::ScoreMode(_)
+            | PopupKind::Settings(_)
+            | PopupKind::Teams(_)
+            | PopupKind::Export(_)
+            | PopupKind::Notifications(_)
+            | PopupKind::Results(_) => {
+                panic!("IMPOSSIBLE: ONLY DRAFTERS CAN UPDATE A LINEUP")
+            }
         }
     }
     pub fn view(&self) -> Element<'_, PopupMessage> {
@@ -85,6 +297,19 @@ impl Popup {
         let main = widget::container(match &self.kind {
             PopupKind::RollOnDrafter(ro) => ro.view().map(PopupMessage::RollOn),
             PopupKind::ReplaceAllDrafter(ra) => ra.view().map(PopupMessage::ReplaceAll),
+            PopupKind::History(h) => h.view().map(PopupMessage::History),
+            PopupKind::Report(r) => r.view(),
+            PopupKind::Calendar(c) => c.view().map(PopupMessage::Calendar),
+            PopupKind::Chart(c) => c.view().map(PopupMessage::Chart),
+            PopupKind::Team(t) => t.view(),
+            PopupKind::Driver(d) => d.view(),
+            PopupKind::WhatIf(w) => w.view().map(PopupMessage::WhatIf),
+            PopupKind::ScoreMode(sm) => sm.view().map(PopupMessage::ScoreMode),
+            PopupKind::Settings(set) => set.view().map(PopupMessage::Settings),
+            PopupKind::Teams(t) => t.view().map(PopupMessage::Teams),
+            PopupKind::Export(ex) => ex.view().map(PopupMessage::Export),
+            PopupKind::Notifications(n) => n.view().map(PopupMessage::Notifications),
+            PopupKind::Results(r) => r.view().map(PopupMessage::Results),
         });
 
         widget::column![top, main].into()
@@ -97,14 +322,84 @@ impl Popup {
                     let action = ro.update(msg);
                     self.handle_action(action)
                 }
-                PopupKind::ReplaceAllDrafter(_) => panic!("RollOn msg passed to ReplaceAll"),
+                _ => panic!("RollOn msg passed to non RollOn popup"),
             },
             PopupMessage::ReplaceAll(msg) => match &mut self.kind {
-                PopupKind::RollOnDrafter(_) => panic!("ReplaceAll msg passed to ReplaceAll"),
                 PopupKind::ReplaceAllDrafter(ra) => {
                     let action = ra.update(msg);
                     self.handle_action(action)
                 }
+                _ => panic!("ReplaceAll msg passed to non ReplaceAll popup"),
+            },
+            PopupMessage::History(msg) => match &mut self.kind {
+                PopupKind::History(h) => {
+                    let action = h.update(msg);
+                    self.handle_action(action)
+                }
+                _ => panic!("History msg passed to non History popup"),
+            },
+            PopupMessage::Calendar(msg) => match &mut self.kind {
+                PopupKind::Calendar(c) => {
+                    let action = c.update(msg);
+                    self.handle_action(action)
+                }
+                _ => panic!("Calendar msg passed to non Calendar popup"),
+            },
+            PopupMessage::Chart(msg) => match &mut self.kind {
+                PopupKind::Chart(c) => {
+                    let action = c.update(msg);
+                    self.handle_action(action)
+                }
+                _ => panic!("Chart msg passed to non Chart popup"),
+            },
+            PopupMessage::WhatIf(msg) => match &mut self.kind {
+                PopupKind::WhatIf(w) => {
+                    let action = w.update(msg);
+                    self.handle_action(action)
+                }
+                _ => panic!("WhatIf msg passed to non WhatIf popup"),
+            },
+            PopupMessage::ScoreMode(msg) => match &mut self.kind {
+                PopupKind::ScoreMode(sm) => {
+                    let action = sm.update(msg);
+                    self.handle_action(action)
+                }
+                _ => panic!("ScoreMode msg passed to non ScoreMode popup"),
+            },
+            PopupMessage::Settings(msg) => match &mut self.kind {
+                PopupKind::Settings(set) => {
+                    let action = set.update(msg);
+                    self.handle_action(action)
+                }
+                _ => panic!("Settings msg passed to non Settings popup"),
+            },
+            PopupMessage::Teams(msg) => match &mut self.kind {
+                PopupKind::Teams(t) => {
+                    let action = t.update(msg);
+                    self.handle_action(action)
+                }
+                _ => panic!("Teams msg passed to non Teams popup"),
+            },
+            PopupMessage::Export(msg) => match &mut self.kind {
+                PopupKind::Export(ex) => {
+                    let action = ex.update(msg);
+                    self.handle_action(action)
+                }
+                _ => panic!("Export msg passed to non Export popup"),
+            },
+            PopupMessage::Notifications(msg) => match &mut self.kind {
+                PopupKind::Notifications(n) => {
+                    let action = n.update(msg);
+                    self.handle_action(action)
+                }
+                _ => panic!("Notifications msg passed to non Notifications popup"),
+            },
+            PopupMessage::Results(msg) => match &mut self.kind {
+                PopupKind::Results(r) => {
+                    let action = r.update(msg);
+                    self.handle_action(action)
+                }
+                _ => panic!("Results msg passed to non Results popup"),
             },
             PopupMessage::Close => SeasonAction::ClosePopup,
         }
@@ -113,16 +408,33 @@ impl Popup {
     fn handle_action(&mut self, action: PopupAction) -> SeasonAction {
         match action {
             PopupAction::UpdateLineup => SeasonAction::UpdateLineup,
+            PopupAction::GoToRound(round) => SeasonAction::GoToRound(round),
+            PopupAction::WhatIf(alternate) => SeasonAction::WhatIf(alternate),
+            PopupAction::ChangeScoreChoice(choice, retroactive) => {
+                SeasonAction::ChangeScoreChoice(choice, retroactive)
+            }
+            PopupAction::ChangeSettings(settings) => SeasonAction::ChangeSettings(settings),
+            PopupAction::AddTeam(name, lineup, handicap) => {
+                SeasonAction::AddTeam(name, lineup, handicap)
+            }
+            PopupAction::RetireTeam(name) => SeasonAction::RetireTeam(name),
+            PopupAction::Export(format, path) => SeasonAction::Export(format, path),
+            PopupAction::AddWebhook(url, format) => SeasonAction::AddWebhook(url, format),
+            PopupAction::RemoveWebhook(idx) => SeasonAction::RemoveWebhook(idx),
+            PopupAction::TestWebhooks => SeasonAction::TestWebhooks,
+            PopupAction::SetResults(results) => SeasonAction::SetResults(results),
             PopupAction::None => SeasonAction::None,
         }
     }
 }
 
-fn lineup_view<T: Debug + Clone + 'static>(
-    mut content: Vec<(String, Vec<Element<T>>)>,
+// the lineup of each team, with anything else the drafter needs below them
+fn lineup_view<'a, T: Debug + Clone + 'static>(
+    mut content: Vec<(String, Vec<Element<'a, T>>)>,
+    footer: Option<Element<'a, T>>,
     can_draft: bool,
     message: T,
-) -> Element<T> {
+) -> Element<'a, T> {
     let mut team_section = Vec::new();
 
     let mut length = 0;
@@ -152,12 +464,37 @@ fn lineup_view<T: Debug + Clone + 'static>(
         widget::vertical_space(),
         widget::Column::from_vec(team_section).spacing(PADDING),
         widget::vertical_space(),
+    ]
+    .push_maybe(footer)
+    .push(
         widget::button("finish")
             .on_press_maybe(can_draft.then_some(message))
             .style(style::button::primary),
-    ]
+    )
     .width(Length::Fill)
     .align_x(Alignment::Center)
     .spacing(PADDING)
     .into()
 }
+
+// a column of a table in a detail popup, with a title above each cell
+fn table_column<'a, T: 'static>(
+    title: &str,
+    cells: Vec<String>,
+    align: Alignment,
+) -> widget::Column<'a, T> {
+    widget::Column::from_iter(
+        std::iter::once(
+            widget::text!("{title}")
+                .size(CONTENT)
+                .style(widget::text::secondary)
+                .into(),
+        )
+        .chain(
+            cells
+                .into_iter()
+                .map(|cell| widget::text!("{cell}").size(CONTENT).into()),
+        ),
+    )
+    .align_x(align)
+}
@@ -0,0 +1,137 @@
+use super::PopupAction;
+use crate::api::ScheduledRace;
+use crate::vc::{CONTENT, PADDING, style};
+use iced::widget::text::secondary;
+use iced::{Alignment, Element, Length, widget};
+use time::OffsetDateTime;
+
+const ROUNDS_PER_ROW: usize = 6;
+const ROUND_WIDTH: f32 = 190.0;
+
+pub struct Calendar {
+    rounds: Vec<CalendarRound>,
+    current_round: u8,
+}
+
+// a round on the calendar, with its (drafted, downloaded, scored) status
+pub struct CalendarRound {
+    pub round: u8,
+    pub race: Option<ScheduledRace>,
+    pub status: (bool, bool, bool),
+}
+
+impl Calendar {
+    pub(super) fn new(rounds: Vec<CalendarRound>, current_round: u8) -> Calendar {
+        Calendar {
+            rounds,
+            current_round,
+        }
+    }
+
+    pub(super) fn view(&self) -> Element<'_, CMessage> {
+        let today = OffsetDateTime::now_utc().date();
+        let next_race = self
+            .rounds
+            .iter()
+            .filter_map(|r| r.race.as_ref().map(|race| (r.round, race)))
+            .find(|(_, race)| race.date >= today);
+
+        let next_race_text = match next_race {
+            Some((round, race)) => format!(
+                "next race: round {round}, {} on {}",
+                race.name,
+                format_date(race)
+            ),
+            None if self.rounds.iter().any(|r| r.race.is_some()) => {
+                String::from("there are no more races this season")
+            }
+            None => String::from("the race schedule has not been downloaded"),
+        };
+
+        let rows = self.rounds.chunks(ROUNDS_PER_ROW).map(|chunk| {
+            widget::Row::from_iter(chunk.iter().map(|r| self.view_round(r)))
+                .spacing(PADDING)
+                .into()
+        });
+
+        widget::column![
+            widget::text!("{next_race_text}").size(CONTENT),
+            Self::view_legend(),
+            widget::scrollable(widget::Column::from_iter(rows).spacing(PADDING))
+                .height(Length::Fill),
+        ]
+        .width(Length::Fill)
+        .align_x(Alignment::Center)
+        .spacing(PADDING)
+        .into()
+    }
+
+    fn view_round<'a>(&self, r: &'a CalendarRound) -> Element<'a, CMessage> {
+        let mut lines = vec![
+            widget::text!(
+                "Round {}{}",
+                r.round,
+                if r.round == self.current_round {
+                    " (viewing)"
+                } else {
+                    ""
+                }
+            )
+            .size(CONTENT)
+            .into(),
+        ];
+        if let Some(race) = &r.race {
+            lines.push(widget::text!("{}", race.name).into());
+            lines.push(widget::text!("{}", race.circuit).into());
+            lines.push(widget::text!("{}, {}", race.country, format_date(race)).into());
+        }
+
+        widget::button(widget::Column::from_vec(lines))
+            .width(ROUND_WIDTH)
+            .on_press(CMessage::GoToRound(r.round))
+            .style(match r.status {
+                (_, _, true) => style::button::success,
+                (_, true, _) => style::button::primary,
+                (true, _, _) => style::button::secondary,
+                _ => style::button::neutral,
+            })
+            .into()
+    }
+
+    fn view_legend<'a>() -> Element<'a, CMessage> {
+        let key = |label: &'a str, style: fn(&iced::Theme, _) -> _| {
+            widget::button(widget::text!("{label}")).style(style)
+        };
+
+        widget::row![
+            widget::text!("key:").style(secondary),
+            key("scored", style::button::success),
+            key("results downloaded", style::button::primary),
+            key("drafted", style::button::secondary),
+            key("not started", style::button::neutral),
+        ]
+        .spacing(PADDING)
+        .align_y(Alignment::Center)
+        .into()
+    }
+
+    pub(super) fn update(&mut self, message: CMessage) -> PopupAction {
+        match message {
+            CMessage::GoToRound(round) => PopupAction::GoToRound(round),
+        }
+    }
+}
+
+fn format_date(race: &ScheduledRace) -> String {
+    format!(
+        "{}-{:02}-{:02}",
+        race.date.year(),
+        race.date.month() as u8,
+        race.date.day()
+    )
+}
+
+#[derive(Clone, Debug)]
+pub enum CMessage {
+    GoToRound(u8),
+}
@@ -0,0 +1,286 @@
+use super::PopupAction;
+use crate::fantasy_season::Standing;
+use crate::vc::{CONTENT, MONO_FONT, PADDING, style};
+use iced::mouse::Cursor;
+use iced::widget::canvas::{self, Frame, Geometry, Path, Stroke, Text};
+use iced::{Alignment, Color, Element, Length, Pixels, Point, Rectangle, Renderer, Theme, widget};
+
+const MARGIN: f32 = 45.0;
+const LABEL_SIZE: f32 = 14.0;
+const POINT_RADIUS: f32 = 4.0;
+const HOVER_DISTANCE: f32 = 12.0;
+
+// colors for each team's line, reused in order if there are more teams than colors
+const TEAM_COLORS: [Color; 10] = [
+    iced::color!(0xFF6319),
+    iced::color!(0xFFBD00),
+    iced::color!(0x4EA8DE),
+    iced::color!(0x80ED99),
+    iced::color!(0xE056FD),
+    iced::color!(0xFCF7F8),
+    iced::color!(0xCE3636),
+    iced::color!(0x2EC4B6),
+    iced::color!(0xA47148),
+    iced::color!(0x9D8DF1),
+];
+
+pub struct Chart {
+    series: Vec<Series>,
+    show_positions: bool,
+}
+
+struct Series {
+    team: String,
+    rounds: Vec<Standing>,
+    visible: bool,
+}
+
+impl Chart {
+    pub(super) fn new(progression: Vec<(String, Vec<Standing>)>) -> Chart {
+        Chart {
+            series: progression
+                .into_iter()
+                .map(|(team, rounds)| Series {
+                    team,
+                    rounds,
+                    visible: true,
+                })
+                .collect(),
+            show_positions: false,
+        }
+    }
+
+    pub(super) fn view(&self) -> Element<'_, ChartMessage> {
+        let toggles = widget::Row::from_iter(self.series.iter().enumerate().map(|(idx, s)| {
+            widget::checkbox(&s.team, s.visible)
+                .on_toggle(move |visible| ChartMessage::ToggleTeam(idx, visible))
+                .text_size(CONTENT)
+                .size(CONTENT)
+                .style(move |theme, status| {
+                    let mut style = widget::checkbox::primary(theme, status);
+                    style.text_color = Some(team_color(idx));
+                    style
+                })
+                .into()
+        }))
+        .spacing(PADDING * 2);
+
+        let content: Element<ChartMessage> = if self.series.iter().all(|s| s.rounds.is_empty()) {
+            widget::text!("no rounds have been scored yet")
+                .size(CONTENT)
+                .into()
+        } else {
+            widget::canvas(self)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .into()
+        };
+
+        widget::column![
+            widget::toggler(self.show_positions)
+                .label("show standings positions instead of points")
+                .on_toggle(ChartMessage::ShowPositions)
+                .text_size(CONTENT)
+                .size(CONTENT),
+            widget::scrollable(toggles).direction(widget::scrollable::Direction::Horizontal(
+                widget::scrollable::Scrollbar::default()
+            )),
+            widget::container(content)
+                .padding(PADDING)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .style(style::container::content),
+        ]
+        .width(Length::Fill)
+        .align_x(Alignment::Center)
+        .spacing(PADDING)
+        .into()
+    }
+
+    pub(super) fn update(&mut self, message: ChartMessage) -> PopupAction {
+        match message {
+            ChartMessage::ToggleTeam(idx, visible) => self.series[idx].visible = visible,
+            ChartMessage::ShowPositions(show) => self.show_positions = show,
+        }
+
+        PopupAction::None
+    }
+
+    // the value plotted on the y-axis for a round
+    fn value(&self, standing: &Standing) -> f32 {
+        if self.show_positions {
+            standing.position as f32
+        } else {
+            standing.points as f32
+        }
+    }
+
+    // the range of rounds and values covered by the visible series
+    fn extent(&self) -> ((u8, u8), (f32, f32)) {
+        let visible = || {
+            self.series
+                .iter()
+                .filter(|s| s.visible)
+                .flat_map(|s| s.rounds.iter())
+        };
+
+        let first = visible().map(|s| s.round).min().unwrap_or(1);
+        let last = visible().map(|s| s.round).max().unwrap_or(1);
+
+        if self.show_positions {
+            ((first, last), (1.0, self.series.len().max(2) as f32))
+        } else {
+            let min = visible().map(|r| self.value(r)).fold(0.0, f32::min);
+            let max = visible().map(|r| self.value(r)).fold(1.0, f32::max);
+            ((first, last), (min, max))
+        }
+    }
+}
+
+impl canvas::Program<ChartMessage> for Chart {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &(),
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let text_color = theme.extended_palette().secondary.weak.text;
+
+        let ((first, last), (min, max)) = self.extent();
+        let width = bounds.width - MARGIN * 2.0;
+        let height = bounds.height - MARGIN * 2.0;
+
+        let to_point = |round: u8, value: f32| {
+            let x = if first == last {
+                0.5
+            } else {
+                (round - first) as f32 / (last - first) as f32
+            };
+            let y = (value - min) / (max - min);
+            // positions are drawn with first place at the top
+            let y = if self.show_positions { y } else { 1.0 - y };
+            Point::new(MARGIN + x * width, MARGIN + y * height)
+        };
+
+        let label = |frame: &mut Frame, content: String, position: Point, color: Color| {
+            frame.fill_text(Text {
+                content,
+                position,
+                color,
+                size: Pixels(LABEL_SIZE),
+                font: MONO_FONT,
+                ..Text::default()
+            });
+        };
+
+        // axes
+        let axis = Stroke::default().with_color(text_color).with_width(1.0);
+        frame.stroke(
+            &Path::line(
+                Point::new(MARGIN, MARGIN),
+                Point::new(MARGIN, MARGIN + height),
+            ),
+            axis,
+        );
+        frame.stroke(
+            &Path::line(
+                Point::new(MARGIN, MARGIN + height),
+                Point::new(MARGIN + width, MARGIN + height),
+            ),
+            axis,
+        );
+        for round in first..=last {
+            let point = to_point(round, min);
+            let point = Point::new(point.x - LABEL_SIZE / 2.0, MARGIN + height + PADDING as f32);
+            label(&mut frame, format!("R{round}"), point, text_color);
+        }
+        let (top, bottom) = if self.show_positions {
+            (format!("P{min}"), format!("P{max}"))
+        } else {
+            (format!("{max}"), format!("{min}"))
+        };
+        label(&mut frame, top, Point::new(0.0, MARGIN), text_color);
+        label(
+            &mut frame,
+            bottom,
+            Point::new(0.0, MARGIN + height - LABEL_SIZE),
+            text_color,
+        );
+
+        // lines, remembering the closest point to the cursor for the tooltip
+        let cursor = cursor.position_in(bounds);
+        let mut hovered: Option<(f32, Point, String)> = None;
+
+        for (idx, series) in self.series.iter().enumerate().filter(|(_, s)| s.visible) {
+            let color = team_color(idx);
+            let points: Vec<_> = series
+                .rounds
+                .iter()
+                .map(|s| (to_point(s.round, self.value(s)), s))
+                .collect();
+
+            let path = Path::new(|builder| {
+                for (pos, (point, _)) in points.iter().enumerate() {
+                    if pos == 0 {
+                        builder.move_to(*point);
+                    } else {
+                        builder.line_to(*point);
+                    }
+                }
+            });
+            frame.stroke(&path, Stroke::default().with_color(color).with_width(2.0));
+
+            for (point, standing) in &points {
+                frame.fill(&Path::circle(*point, POINT_RADIUS), color);
+
+                if let Some(cursor) = cursor {
+                    let distance = cursor.distance(*point);
+                    if distance < HOVER_DISTANCE && hovered.as_ref().is_none_or(|h| distance < h.0)
+                    {
+                        hovered = Some((
+                            distance,
+                            *point,
+                            format!(
+                                "{}: round {}, {} points, P{}",
+                                series.team, standing.round, standing.points, standing.position
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+
+        if let Some((_, point, content)) = hovered {
+            let text_width = content.len() as f32 * LABEL_SIZE * 0.6;
+            let x = point.x.min(bounds.width - text_width - PADDING as f32);
+            let background = Path::rectangle(
+                Point::new(x - 4.0, point.y - LABEL_SIZE * 2.0 - 4.0),
+                iced::Size::new(text_width + 8.0, LABEL_SIZE + 8.0),
+            );
+            frame.fill(&background, theme.extended_palette().secondary.strong.color);
+            label(
+                &mut frame,
+                content,
+                Point::new(x, point.y - LABEL_SIZE * 2.0),
+                theme.extended_palette().secondary.strong.text,
+            );
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+fn team_color(idx: usize) -> Color {
+    TEAM_COLORS[idx % TEAM_COLORS.len()]
+}
+
+#[derive(Clone, Debug)]
+pub enum ChartMessage {
+    ToggleTeam(usize, bool),
+    ShowPositions(bool),
+}
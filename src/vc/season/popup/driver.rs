@@ -0,0 +1,89 @@
+use super::table_column;
+use crate::fantasy_season::report::DriverReport;
+use crate::vc::{CONTENT, PADDING, style};
+use iced::{Alignment, Element, Length, widget};
+
+pub struct DriverDetail {
+    report: DriverReport,
+}
+
+impl DriverDetail {
+    pub(super) fn new(report: DriverReport) -> DriverDetail {
+        DriverDetail { report }
+    }
+
+    pub(super) fn view<T: 'static>(&self) -> Element<'_, T> {
+        let fmt_avg = |avg: Option<f32>| match avg {
+            Some(avg) => format!("{avg:.1}"),
+            None => String::from("-"),
+        };
+
+        let summary = widget::column![
+            widget::text!("average finish: {}", fmt_avg(self.report.average_finish)).size(CONTENT),
+            widget::text!("positions gained: {}", self.report.positions_gained).size(CONTENT),
+            widget::text!(
+                "points per round owned: {}",
+                fmt_avg(self.report.points_per_round_owned)
+            )
+            .size(CONTENT),
+        ];
+
+        let rounds = &self.report.rounds;
+        let number_column = |title: &str, cells: Vec<u8>| {
+            table_column(
+                title,
+                cells.into_iter().map(|c| c.to_string()).collect(),
+                Alignment::End,
+            )
+        };
+        let table = widget::row![
+            number_column("round", rounds.iter().map(|r| r.round).collect()),
+            number_column(
+                "qualifying",
+                rounds.iter().map(|r| r.qualifying_position).collect()
+            ),
+            number_column("grid", rounds.iter().map(|r| r.grid_position).collect()),
+            number_column("finish", rounds.iter().map(|r| r.final_position).collect()),
+            table_column(
+                "points",
+                rounds.iter().map(|r| r.points.to_string()).collect(),
+                Alignment::End
+            ),
+            table_column(
+                "owned by",
+                rounds
+                    .iter()
+                    .map(|r| {
+                        if r.owners.is_empty() {
+                            String::from("-")
+                        } else {
+                            r.owners.join(", ")
+                        }
+                    })
+                    .collect(),
+                Alignment::Start
+            ),
+        ]
+        .spacing(PADDING * 3);
+
+        let content: Element<T> = if rounds.is_empty() {
+            widget::text!("no results for this driver yet")
+                .size(CONTENT)
+                .into()
+        } else {
+            widget::scrollable(table).into()
+        };
+
+        widget::column![
+            summary,
+            widget::container(content)
+                .padding(PADDING)
+                .height(Length::Fill)
+                .style(style::container::content),
+        ]
+        .width(Length::Fill)
+        .align_x(Alignment::Center)
+        .spacing(PADDING)
+        .into()
+    }
+}
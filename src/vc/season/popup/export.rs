@@ -0,0 +1,113 @@
+use super::PopupAction;
+use crate::export::ExportFormat;
+use crate::vc::{CONTENT, PADDING, style};
+use iced::widget::text::{danger, secondary};
+use iced::{Alignment, Element, Length, widget};
+use std::path::PathBuf;
+
+pub struct Export {
+    round: u8,
+    format: ExportFormat,
+    path: String,
+    error: Option<String>,
+}
+
+impl Export {
+    // the report is written to the documents folder by default, named after the season and round
+    pub(super) fn new(round: u8, season_name: &str) -> Export {
+        let format = ExportFormat::Markdown;
+        let mut path = directories_next::UserDirs::new()
+            .and_then(|dirs| {
+                dirs.document_dir()
+                    .map(PathBuf::from)
+                    .or_else(|| Some(dirs.home_dir().to_path_buf()))
+            })
+            .unwrap_or_default();
+        path.push(format!(
+            "{season_name} round {round}.{}",
+            format.extension()
+        ));
+
+        Export {
+            round,
+            format,
+            path: path.to_string_lossy().to_string(),
+            error: None,
+        }
+    }
+
+    pub(super) fn set_error(&mut self, error: String) {
+        self.error = Some(error);
+    }
+
+    pub(super) fn view(&self) -> Element<'_, ExMessage> {
+        let format = widget::pick_list(
+            vec![
+                ExportFormat::Csv,
+                ExportFormat::Markdown,
+                ExportFormat::Html,
+                ExportFormat::Png,
+            ],
+            Some(self.format),
+            ExMessage::FormatSelected,
+        )
+        .style(style::pick_list::default)
+        .menu_style(style::pick_list::default_menu)
+        .text_size(CONTENT);
+
+        widget::column![
+            widget::text!(
+                "the standings, lineups, and driver performance for round {}",
+                self.round
+            )
+            .size(CONTENT)
+            .style(secondary),
+            widget::row![
+                format,
+                widget::text_input("path to write to", &self.path)
+                    .on_input(ExMessage::ChangePath)
+                    .size(CONTENT)
+                    .style(style::text_input::default),
+            ]
+            .spacing(PADDING)
+            .align_y(Alignment::Center),
+            widget::vertical_space(),
+            widget::text!("{}", self.error.as_deref().unwrap_or_default())
+                .size(CONTENT)
+                .style(danger),
+            widget::button("export")
+                .on_press_maybe((!self.path.trim().is_empty()).then_some(ExMessage::Export))
+                .style(style::button::primary),
+        ]
+        .width(Length::Fill)
+        .align_x(Alignment::Center)
+        .spacing(PADDING)
+        .into()
+    }
+
+    pub(super) fn update(&mut self, message: ExMessage) -> PopupAction {
+        match message {
+            ExMessage::FormatSelected(format) => {
+                // a path with the old format's extension is given the new one
+                let old_extension = format!(".{}", self.format.extension());
+                if let Some(stem) = self.path.strip_suffix(&old_extension) {
+                    self.path = format!("{stem}.{}", format.extension());
+                }
+                self.format = format;
+            }
+            ExMessage::ChangePath(path) => self.path = path,
+            ExMessage::Export => {
+                return PopupAction::Export(self.format, PathBuf::from(self.path.trim()));
+            }
+        }
+
+        PopupAction::None
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum ExMessage {
+    FormatSelected(ExportFormat),
+    ChangePath(String),
+    Export,
+}
@@ -0,0 +1,82 @@
+use super::PopupAction;
+use crate::fantasy_season::history::HistoryEntry;
+use crate::vc::{CONTENT, PADDING, style};
+use iced::widget::text::secondary;
+use iced::{Alignment, Element, Length, widget};
+
+pub struct History {
+    entries: Vec<HistoryEntry>,
+    round: u8,
+    only_round: bool,
+}
+
+impl History {
+    pub(super) fn new(entries: Vec<HistoryEntry>, round: u8) -> History {
+        History {
+            entries,
+            round,
+            only_round: true,
+        }
+    }
+
+    pub(super) fn view(&self) -> Element<'_, HMessage> {
+        let entries: Vec<Element<HMessage>> = self
+            .entries
+            .iter()
+            .rev()
+            .filter(|e| !self.only_round || e.round() == self.round)
+            .map(|e| {
+                let mut lines = vec![
+                    widget::text!("{}  round {}  {}", e.time(), e.round(), e.event())
+                        .size(CONTENT)
+                        .into(),
+                ];
+                lines.extend(e.event().details().into_iter().map(|detail| {
+                    widget::text!("    {detail}")
+                        .size(CONTENT)
+                        .style(secondary)
+                        .into()
+                }));
+                widget::Column::from_vec(lines).into()
+            })
+            .collect();
+
+        let content: Element<HMessage> = if entries.is_empty() {
+            widget::text!("nothing has happened yet")
+                .size(CONTENT)
+                .into()
+        } else {
+            widget::scrollable(widget::Column::from_vec(entries).spacing(PADDING))
+                .height(Length::Fill)
+                .into()
+        };
+
+        widget::column![
+            widget::toggler(self.only_round)
+                .label(format!("only show round {}", self.round))
+                .on_toggle(HMessage::ToggleOnlyRound)
+                .text_size(CONTENT)
+                .size(CONTENT),
+            widget::container(content)
+                .padding(PADDING)
+                .style(style::container::content),
+        ]
+        .width(Length::Fill)
+        .align_x(Alignment::Center)
+        .spacing(PADDING)
+        .into()
+    }
+
+    pub(super) fn update(&mut self, message: HMessage) -> PopupAction {
+        match message {
+            HMessage::ToggleOnlyRound(only_round) => self.only_round = only_round,
+        }
+
+        PopupAction::None
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum HMessage {
+    ToggleOnlyRound(bool),
+}
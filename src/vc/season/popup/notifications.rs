@@ -0,0 +1,134 @@
+use super::PopupAction;
+use crate::fantasy_season::notification::{Webhook, WebhookFormat};
+use crate::vc::{CONTENT, PADDING, style};
+use iced::widget::text::{danger, secondary};
+use iced::{Alignment, Element, Length, widget};
+
+pub struct Notifications {
+    webhooks: Vec<Webhook>,
+    url: String,
+    format: WebhookFormat,
+    error: Option<String>,
+    test_result: Option<String>,
+}
+
+impl Notifications {
+    pub(super) fn new(webhooks: Vec<Webhook>) -> Notifications {
+        Notifications {
+            webhooks,
+            url: String::new(),
+            format: WebhookFormat::default(),
+            error: None,
+            test_result: None,
+        }
+    }
+
+    pub(super) fn set_error(&mut self, error: String) {
+        self.error = Some(error);
+    }
+
+    pub(super) fn set_test_result(&mut self, result: Result<(), String>) {
+        match result {
+            Ok(()) => self.test_result = Some(String::from("the test notification was sent")),
+            Err(err) => self.error = Some(err),
+        }
+    }
+
+    pub(super) fn view(&self) -> Element<'_, NMessage> {
+        let webhooks =
+            widget::Column::from_iter(self.webhooks.iter().enumerate().map(|(idx, webhook)| {
+                widget::row![
+                    widget::text!("{}", webhook.get_format())
+                        .size(CONTENT)
+                        .style(secondary)
+                        .width(150),
+                    widget::text!("{}", webhook.get_url())
+                        .size(CONTENT)
+                        .width(Length::Fill),
+                    widget::button(widget::text!("remove").size(CONTENT))
+                        .on_press(NMessage::Remove(idx))
+                        .style(style::button::danger),
+                ]
+                .spacing(PADDING)
+                .align_y(Alignment::Center)
+                .into()
+            }))
+            .spacing(PADDING);
+
+        let new_webhook = widget::row![
+            widget::pick_list(
+                vec![
+                    WebhookFormat::Generic,
+                    WebhookFormat::Discord,
+                    WebhookFormat::Slack,
+                ],
+                Some(self.format),
+                NMessage::FormatSelected,
+            )
+            .style(style::pick_list::default)
+            .menu_style(style::pick_list::default_menu)
+            .text_size(CONTENT),
+            widget::text_input("webhook url", &self.url)
+                .on_input(NMessage::ChangeUrl)
+                .size(CONTENT)
+                .style(style::text_input::default),
+            widget::button(widget::text!("add").size(CONTENT))
+                .on_press_maybe((!self.url.trim().is_empty()).then_some(NMessage::Add))
+                .style(style::button::secondary),
+        ]
+        .spacing(PADDING)
+        .align_y(Alignment::Center);
+
+        widget::column![
+            widget::container(widget::scrollable(webhooks))
+                .padding(PADDING)
+                .max_height(300)
+                .style(style::container::content),
+            widget::text!(
+                "each webhook is sent a message when a round is scored or its results become available"
+            )
+            .size(CONTENT)
+            .style(secondary),
+            new_webhook,
+            widget::vertical_space(),
+            widget::text!("{}", self.test_result.as_deref().unwrap_or_default())
+                .size(CONTENT)
+                .style(secondary),
+            widget::text!("{}", self.error.as_deref().unwrap_or_default())
+                .size(CONTENT)
+                .style(danger),
+            widget::button("send test")
+                .on_press_maybe((!self.webhooks.is_empty()).then_some(NMessage::Test))
+                .style(style::button::primary),
+        ]
+        .width(Length::Fill)
+        .align_x(Alignment::Center)
+        .spacing(PADDING)
+        .into()
+    }
+
+    pub(super) fn update(&mut self, message: NMessage) -> PopupAction {
+        self.test_result = None;
+        match message {
+            NMessage::FormatSelected(format) => self.format = format,
+            NMessage::ChangeUrl(url) => self.url = url,
+            NMessage::Add => return PopupAction::AddWebhook(self.url.clone(), self.format),
+            NMessage::Remove(idx) => return PopupAction::RemoveWebhook(idx),
+            NMessage::Test => {
+                self.error = None;
+                return PopupAction::TestWebhooks;
+            }
+        }
+
+        PopupAction::None
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum NMessage {
+    FormatSelected(WebhookFormat),
+    ChangeUrl(String),
+    Add,
+    Remove(usize),
+    Test,
+}
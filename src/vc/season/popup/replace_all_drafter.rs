@@ -1,13 +1,17 @@
 use crate::fantasy_season::draft;
+use crate::fantasy_season::error::ImportError;
 use crate::vc::season::popup::PopupAction;
 use crate::vc::utils::*;
 use crate::vc::{CONTENT, style};
+use iced::widget::text_editor;
 use iced::{Element, widget};
 use std::collections::HashMap;
 
 pub struct ReplaceAllDrafter {
     team_lineups: HashMap<String, Vec<String>>,
     enforce_uniqueness: bool,
+    import: text_editor::Content,
+    import_error: Option<String>,
 }
 
 impl ReplaceAllDrafter {
@@ -21,10 +25,7 @@ impl ReplaceAllDrafter {
             team_lineups.insert(team, vec![String::new(); team_size]);
         }
 
-        ReplaceAllDrafter {
-            team_lineups,
-            enforce_uniqueness,
-        }
+        ReplaceAllDrafter::from(team_lineups, enforce_uniqueness)
     }
 
     pub(super) fn from(
@@ -34,8 +35,11 @@ impl ReplaceAllDrafter {
         ReplaceAllDrafter {
             team_lineups,
             enforce_uniqueness,
+            import: text_editor::Content::new(),
+            import_error: None,
         }
     }
+
     pub(super) fn view(&self) -> Element<'_, RAMessage> {
         let content = self
             .team_lineups
@@ -60,7 +64,34 @@ impl ReplaceAllDrafter {
             })
             .collect();
 
-        super::lineup_view(content, self.can_draft(), RAMessage::UpdateLineup)
+        // a lineup that breaks uniqueness is explained, as it is otherwise only shown by the
+        // finish button being disabled
+        let error = self.import_error.clone().or_else(|| {
+            self.enforce_uniqueness
+                .then(|| {
+                    find_duplicate(
+                        self.team_lineups
+                            .values()
+                            .flatten()
+                            .filter(|d| !d.is_empty()),
+                    )
+                })
+                .flatten()
+                .map(|d| format!("driver #{d} is in more than one lineup"))
+        });
+        let import = crate::vc::import_view(
+            &self.import,
+            RAMessage::EditImport,
+            RAMessage::Import,
+            error,
+        );
+
+        super::lineup_view(
+            content,
+            Some(import),
+            self.can_draft(),
+            RAMessage::UpdateLineup,
+        )
     }
 
     pub(super) fn update(&mut self, message: RAMessage) -> PopupAction {
@@ -77,12 +108,40 @@ impl ReplaceAllDrafter {
                     );
                 }
             }
+            RAMessage::EditImport(action) => {
+                if action.is_edit() {
+                    self.import_error = None;
+                }
+                self.import.perform(action);
+            }
+            RAMessage::Import => {
+                self.import_error = self.import_lineups().err().map(|ie| ie.to_string());
+            }
             RAMessage::UpdateLineup => return PopupAction::UpdateLineup,
         }
 
         PopupAction::None
     }
 
+    // fills in the lineups pasted into the import box, leaving the other teams as they are
+    fn import_lineups(&mut self) -> Result<(), ImportError> {
+        let lineups = draft::parse_lineups(&self.import.text())?;
+        let team_names: Vec<_> = self.team_lineups.keys().cloned().collect();
+        let lineup_size = self.team_lineups.values().next().map_or(0, Vec::len);
+        draft::check_lineups(
+            &lineups,
+            Some(&team_names),
+            lineup_size,
+            self.enforce_uniqueness,
+        )?;
+
+        for (team, drivers) in lineups {
+            self.team_lineups
+                .insert(team, drivers.iter().map(u8::to_string).collect());
+        }
+        Ok(())
+    }
+
     fn can_draft(&self) -> bool {
         if self.enforce_uniqueness && !is_unique_lineups(self.team_lineups.values().flatten()) {
             return false;
@@ -110,5 +169,7 @@ impl ReplaceAllDrafter {
 #[derive(Clone, Debug)]
 pub enum RAMessage {
     ChangeDriverNumber(String, usize, String),
+    EditImport(text_editor::Action),
+    Import,
     UpdateLineup,
 }
@@ -0,0 +1,32 @@
+use crate::vc::{CONTENT, PADDING, style};
+use iced::{Alignment, Element, Length, widget};
+
+pub struct Report {
+    lines: Vec<String>,
+}
+
+impl Report {
+    pub(super) fn new(lines: Vec<String>) -> Report {
+        Report { lines }
+    }
+
+    pub(super) fn view<T: 'static>(&self) -> Element<'_, T> {
+        widget::column![
+            widget::vertical_space(),
+            widget::container(
+                widget::Column::from_iter(
+                    self.lines
+                        .iter()
+                        .map(|line| widget::text!("{line}").size(CONTENT).into())
+                )
+                .spacing(PADDING)
+            )
+            .padding(PADDING)
+            .style(style::container::content),
+            widget::vertical_space(),
+        ]
+        .width(Length::Fill)
+        .align_x(Alignment::Center)
+        .into()
+    }
+}
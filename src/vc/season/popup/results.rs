@@ -0,0 +1,203 @@
+use super::PopupAction;
+use crate::fantasy_season::race_results::DriverResult;
+use crate::vc::utils::*;
+use crate::vc::{CONTENT, PADDING, style};
+use iced::widget::text::{danger, secondary};
+use iced::{Alignment, Element, Length, widget};
+
+const POSITION_WIDTH: u16 = 90;
+
+pub struct ResultsEditor {
+    rows: Vec<ResultRow>,
+    grid_size: u8,
+    error: Option<String>,
+}
+
+// the text of each input in a row, kept as typed until the results are saved
+#[derive(Default)]
+struct ResultRow {
+    driver: String,
+    finish: String,
+    grid: String,
+    qualifying: String,
+}
+
+impl ResultsEditor {
+    // starts from the round's results if it has any, otherwise from an empty row for every place
+    // on the grid
+    pub(super) fn new(results: Vec<(u8, DriverResult)>, grid_size: u8) -> ResultsEditor {
+        let rows = if results.is_empty() {
+            (1..=grid_size)
+                .map(|position| ResultRow {
+                    finish: position.to_string(),
+                    ..ResultRow::default()
+                })
+                .collect()
+        } else {
+            results
+                .into_iter()
+                .map(|(driver, result)| ResultRow {
+                    driver: driver.to_string(),
+                    finish: result.final_position.to_string(),
+                    grid: result.grid_position.to_string(),
+                    qualifying: result.qualifying_position.to_string(),
+                })
+                .collect()
+        };
+
+        ResultsEditor {
+            rows,
+            grid_size,
+            error: None,
+        }
+    }
+
+    pub(super) fn set_error(&mut self, error: String) {
+        self.error = Some(error);
+    }
+
+    pub(super) fn view(&self) -> Element<'_, REMessage> {
+        let header = widget::row![
+            widget::text!("driver")
+                .size(CONTENT)
+                .style(secondary)
+                .width(POSITION_WIDTH),
+            widget::text!("finish")
+                .size(CONTENT)
+                .style(secondary)
+                .width(POSITION_WIDTH),
+            widget::text!("grid")
+                .size(CONTENT)
+                .style(secondary)
+                .width(POSITION_WIDTH),
+            widget::text!("qualifying")
+                .size(CONTENT)
+                .style(secondary)
+                .width(POSITION_WIDTH),
+        ]
+        .spacing(PADDING);
+
+        let rows = widget::Column::from_iter(self.rows.iter().enumerate().map(|(idx, row)| {
+            let input =
+                |placeholder: &str, value: &str, message: fn(usize, String) -> REMessage| {
+                    widget::text_input(placeholder, value)
+                        .on_input(move |text| message(idx, text))
+                        .style(style::text_input::default)
+                        .size(CONTENT)
+                        .width(POSITION_WIDTH)
+                };
+            widget::row![
+                input("#", &row.driver, REMessage::ChangeDriver),
+                input("finish", &row.finish, REMessage::ChangeFinish),
+                input("grid", &row.grid, REMessage::ChangeGrid),
+                input("qualifying", &row.qualifying, REMessage::ChangeQualifying),
+                widget::button(widget::text!("remove").size(CONTENT))
+                    .on_press(REMessage::Remove(idx))
+                    .style(style::button::danger),
+            ]
+            .spacing(PADDING)
+            .align_y(Alignment::Center)
+            .into()
+        }))
+        .spacing(PADDING);
+
+        widget::column![
+            header,
+            widget::container(widget::scrollable(rows))
+                .padding(PADDING)
+                .max_height(400)
+                .style(style::container::content),
+            widget::button(widget::text!("add driver").size(CONTENT))
+                .on_press(REMessage::Add)
+                .style(style::button::secondary),
+            widget::text!(
+                "every one of the {} places on the grid needs a result. Drivers starting from the pit lane have a grid position of 0",
+                self.grid_size
+            )
+            .size(CONTENT)
+            .style(secondary),
+            widget::vertical_space(),
+            widget::text!("{}", self.error.as_deref().unwrap_or_default())
+                .size(CONTENT)
+                .style(danger),
+            widget::button("save results")
+                .on_press_maybe(self.get_results().map(|_| REMessage::Save))
+                .style(style::button::primary),
+        ]
+        .width(Length::Fill)
+        .align_x(Alignment::Center)
+        .spacing(PADDING)
+        .into()
+    }
+
+    pub(super) fn update(&mut self, message: REMessage) -> PopupAction {
+        self.error = None;
+        match message {
+            REMessage::ChangeDriver(idx, num) => {
+                if is_valid_driver_input(&num) {
+                    self.rows[idx].driver = num;
+                }
+            }
+            REMessage::ChangeFinish(idx, pos) => {
+                if is_valid_position_input(&pos) {
+                    self.rows[idx].finish = pos;
+                }
+            }
+            REMessage::ChangeGrid(idx, pos) => {
+                if is_valid_position_input(&pos) {
+                    self.rows[idx].grid = pos;
+                }
+            }
+            REMessage::ChangeQualifying(idx, pos) => {
+                if is_valid_position_input(&pos) {
+                    self.rows[idx].qualifying = pos;
+                }
+            }
+            REMessage::Add => self.rows.push(ResultRow::default()),
+            REMessage::Remove(idx) => {
+                self.rows.remove(idx);
+            }
+            REMessage::Save => {
+                if let Some(results) = self.get_results() {
+                    return PopupAction::SetResults(results);
+                }
+            }
+        }
+
+        PopupAction::None
+    }
+
+    // the results if every input has been filled in. Whether they make a valid race is checked by
+    // the season when they are saved
+    fn get_results(&self) -> Option<Vec<(u8, DriverResult)>> {
+        self.rows
+            .iter()
+            .map(|row| {
+                let driver = is_parsable_driver(&row.driver).then(|| row.driver.parse().ok())??;
+                Some((
+                    driver,
+                    DriverResult::new(
+                        row.finish.parse().ok()?,
+                        row.grid.parse().ok()?,
+                        row.qualifying.parse().ok()?,
+                    ),
+                ))
+            })
+            .collect()
+    }
+}
+
+fn is_valid_position_input(new: &str) -> bool {
+    new.is_empty() || new.parse::<u8>().is_ok()
+}
+
+#[derive(Clone, Debug)]
+pub enum REMessage {
+    ChangeDriver(usize, String),
+    ChangeFinish(usize, String),
+    ChangeGrid(usize, String),
+    ChangeQualifying(usize, String),
+    Add,
+    Remove(usize),
+    Save,
+}
@@ -7,66 +7,110 @@ use iced::{Alignment, Element};
 use std::collections::HashMap;
 
 pub struct RollOnDrafter {
-    returning_lineup: HashMap<String, Vec<u8>>,
-    drivers: HashMap<String, String>,
+    previous_lineup: HashMap<String, Vec<u8>>,
+    released_drivers: HashMap<String, Vec<u8>>,
+    releases_needed: HashMap<String, usize>,
+    drivers: HashMap<String, Vec<String>>,
+    team_chooses: bool,
     enforce_uniqueness: bool,
 }
 
 impl RollOnDrafter {
+    // without releases, each team chooses which of its drivers leave
     pub(super) fn new(
-        mut previous_lineup: HashMap<String, Vec<u8>>,
+        previous_lineup: HashMap<String, Vec<u8>>,
+        releases: Option<HashMap<String, Vec<u8>>>,
+        lineup_size: usize,
         enforce_uniqueness: bool,
     ) -> RollOnDrafter {
-        previous_lineup.values_mut().for_each(|x| {
-            x.pop();
-        });
+        let mut drivers = HashMap::new();
+        let mut releases_needed = HashMap::new();
+        for (team, lineup) in &previous_lineup {
+            let picks = draft::RollOn::picks_needed(lineup.len(), lineup_size);
+            drivers.insert(team.clone(), vec![String::new(); picks]);
+            releases_needed.insert(
+                team.clone(),
+                draft::RollOn::releases_needed(lineup.len(), lineup_size),
+            );
+        }
         RollOnDrafter {
-            returning_lineup: previous_lineup,
-            drivers: HashMap::new(),
+            team_chooses: releases.is_none(),
+            released_drivers: releases.unwrap_or_default(),
+            previous_lineup,
+            releases_needed,
+            drivers,
             enforce_uniqueness,
         }
     }
+
     pub(super) fn view(&self) -> Element<'_, ROMessage> {
         let content = self
-            .returning_lineup
-            .keys()
-            .map(|team_name| {
+            .previous_lineup
+            .iter()
+            .map(|(team_name, lineup)| {
                 let mut row = Vec::new();
 
-                row.push(
-                    widget::text_input(
-                        "#1",
-                        self.drivers.get(team_name).unwrap_or(&String::from("")),
-                    )
-                    .size(CONTENT)
-                    .style(style::text_input::default)
-                    .on_input(move |num| ROMessage::ChangeDriverNumber(team_name.to_string(), num))
-                    .width(50)
-                    .into(),
-                );
-
-                for driver in self.returning_lineup.get(team_name).unwrap() {
+                for (idx, num) in self.drivers.get(team_name).unwrap().iter().enumerate() {
                     row.push(
-                        widget::text!("{:02}", driver)
+                        widget::text_input(&format!("#{}", idx + 1), num)
+                            .size(CONTENT)
+                            .style(style::text_input::default)
+                            .on_input(move |num| {
+                                ROMessage::ChangeDriverNumber(team_name.to_string(), idx, num)
+                            })
+                            .width(50)
+                            .into(),
+                    );
+                }
+
+                for driver in lineup {
+                    let leaving = self.is_released(team_name, *driver);
+                    if self.team_chooses {
+                        row.push(
+                            widget::button(widget::text!("{:02}", driver).size(CONTENT))
+                                .on_press(ROMessage::ToggleRelease(team_name.clone(), *driver))
+                                .style(if leaving {
+                                    style::button::danger
+                                } else {
+                                    style::button::secondary
+                                })
+                                .into(),
+                        );
+                    } else {
+                        row.push(
+                            widget::text!(
+                                "{:02}{}",
+                                driver,
+                                if leaving { " (leaving)" } else { "" }
+                            )
                             .size(CONTENT)
                             .align_y(Alignment::Center)
                             .height(CONTENT_INPUT_PADDED)
                             .into(),
-                    );
+                        );
+                    }
                 }
 
                 (team_name.clone(), row)
             })
             .collect();
 
-        super::lineup_view(content, self.can_draft(), ROMessage::UpdateLineup)
+        super::lineup_view(content, None, self.can_draft(), ROMessage::UpdateLineup)
     }
 
     pub(super) fn update(&mut self, message: ROMessage) -> PopupAction {
         match message {
-            ROMessage::ChangeDriverNumber(team, num) => {
+            ROMessage::ChangeDriverNumber(team, idx, num) => {
                 if is_valid_driver_input(&num) {
-                    self.drivers.insert(team, num);
+                    self.drivers.get_mut(&team).unwrap()[idx] = num;
+                }
+            }
+            ROMessage::ToggleRelease(team, driver) => {
+                let released = self.released_drivers.entry(team).or_default();
+                if let Some(idx) = released.iter().position(|d| *d == driver) {
+                    released.remove(idx);
+                } else {
+                    released.push(driver);
                 }
             }
             ROMessage::UpdateLineup => return PopupAction::UpdateLineup,
@@ -80,32 +124,55 @@ impl RollOnDrafter {
             draft::RollOn::new(
                 self.drivers
                     .into_iter()
-                    .map(|(k, v)| (k, v.parse::<u8>().unwrap()))
+                    .map(|(k, v)| (k, v.iter().map(|d| d.parse::<u8>().unwrap()).collect()))
                     .collect(),
+                self.released_drivers,
             )
         } else {
             todo!()
         }
     }
 
+    fn is_released(&self, team: &str, driver: u8) -> bool {
+        self.released_drivers
+            .get(team)
+            .is_some_and(|r| r.contains(&driver))
+    }
+
     fn can_draft(&self) -> bool {
+        let returning_drivers = self.previous_lineup.iter().flat_map(|(team, lineup)| {
+            lineup
+                .iter()
+                .copied()
+                .filter(|d| !self.is_released(team, *d))
+        });
+
         self.drivers
-            .iter()
-            .all(|(_team, num)| is_parsable_driver(num))
-            && self.drivers.len() == self.returning_lineup.len()
+            .values()
+            .flatten()
+            .all(|num| is_parsable_driver(num))
+            && self.releases_needed.iter().all(|(team, needed)| {
+                self.released_drivers
+                    .get(team)
+                    .map(Vec::len)
+                    .unwrap_or_default()
+                    == *needed
+            })
             && (!self.enforce_uniqueness
                 || is_unique_lineups(
-                    self.returning_lineup
-                        .values()
-                        .flatten()
-                        .copied()
-                        .chain(self.drivers.values().map(|x| x.parse::<u8>().unwrap())),
+                    returning_drivers.chain(
+                        self.drivers
+                            .values()
+                            .flatten()
+                            .map(|x| x.parse::<u8>().unwrap()),
+                    ),
                 ))
     }
 }
 
 #[derive(Clone, Debug)]
 pub enum ROMessage {
-    ChangeDriverNumber(String, String),
+    ChangeDriverNumber(String, usize, String),
+    ToggleRelease(String, u8),
     UpdateLineup,
 }
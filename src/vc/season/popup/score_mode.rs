@@ -0,0 +1,113 @@
+use super::PopupAction;
+use crate::fantasy_season::score::{ScoreChange, ScoreChoice};
+use crate::vc::{CONTENT, PADDING, style};
+use iced::widget::text::secondary;
+use iced::{Alignment, Element, Length, widget};
+
+pub struct ScoreMode {
+    round: u8,
+    current: ScoreChoice,
+    changes: Vec<ScoreChange>,
+    choice: Option<ScoreChoice>,
+    retroactive: bool,
+}
+
+impl ScoreMode {
+    pub(super) fn new(round: u8, current: ScoreChoice, changes: Vec<ScoreChange>) -> ScoreMode {
+        ScoreMode {
+            round,
+            current,
+            changes,
+            choice: None,
+            retroactive: false,
+        }
+    }
+
+    pub(super) fn view(&self) -> Element<'_, SMMessage> {
+        let score_mode = widget::pick_list(
+            vec![
+                ScoreChoice::FormulaOne,
+                ScoreChoice::RacePosition,
+                ScoreChoice::Improvement,
+                ScoreChoice::Domination,
+                ScoreChoice::DoubleImprovement,
+            ],
+            self.choice,
+            SMMessage::ChoiceSelected,
+        )
+        .placeholder("Score Mode")
+        .style(style::pick_list::default)
+        .menu_style(style::pick_list::default_menu)
+        .text_size(CONTENT);
+
+        let changes: Vec<Element<SMMessage>> = self
+            .changes
+            .iter()
+            .map(|c| {
+                let scope = if c.retroactive {
+                    String::from("every round")
+                } else {
+                    format!("round {} onward", c.round)
+                };
+                widget::text!("{} for {scope}", c.choice)
+                    .size(CONTENT)
+                    .into()
+            })
+            .collect();
+
+        let changes: Element<SMMessage> = if changes.is_empty() {
+            widget::text!("the score mode has never been changed")
+                .size(CONTENT)
+                .style(secondary)
+                .into()
+        } else {
+            widget::Column::from_vec(changes).into()
+        };
+
+        widget::column![
+            widget::vertical_space(),
+            widget::text!("round {} is scored with {}", self.round, self.current).size(CONTENT),
+            widget::container(changes)
+                .padding(PADDING)
+                .style(style::container::content),
+            score_mode,
+            widget::toggler(self.retroactive)
+                .label(format!(
+                    "rescore every round, not just round {} onward",
+                    self.round
+                ))
+                .on_toggle(SMMessage::ToggleRetroactive)
+                .text_size(CONTENT)
+                .size(CONTENT),
+            widget::vertical_space(),
+            widget::button("change")
+                .on_press_maybe(self.choice.map(|_| SMMessage::Change))
+                .style(style::button::primary),
+        ]
+        .width(Length::Fill)
+        .align_x(Alignment::Center)
+        .spacing(PADDING)
+        .into()
+    }
+
+    pub(super) fn update(&mut self, message: SMMessage) -> PopupAction {
+        match message {
+            SMMessage::ChoiceSelected(choice) => self.choice = Some(choice),
+            SMMessage::ToggleRetroactive(retroactive) => self.retroactive = retroactive,
+            SMMessage::Change => {
+                if let Some(choice) = self.choice {
+                    return PopupAction::ChangeScoreChoice(choice, self.retroactive);
+                }
+            }
+        }
+
+        PopupAction::None
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum SMMessage {
+    ChoiceSelected(ScoreChoice),
+    ToggleRetroactive(bool),
+    Change,
+}
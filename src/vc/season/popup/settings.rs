@@ -0,0 +1,249 @@
+use super::PopupAction;
+use crate::fantasy_season::draft::{DraftChoice, DraftSchedule, RollOnChoice, ScheduleChoice};
+use crate::fantasy_season::settings::SeasonSettings;
+use crate::vc::{CONTENT, PADDING, style};
+use iced::widget::text::{danger, secondary};
+use iced::{Alignment, Element, Length, widget};
+
+pub struct Settings {
+    round: u8,
+    name: String,
+    team_names: Vec<String>,
+    lineup_size: String,
+    grid_size: String,
+    draft_choice: DraftChoice,
+    roll_on_choice: RollOnChoice,
+    schedule_choice: ScheduleChoice,
+    schedule_input: String,
+    enforce_uniqueness: bool,
+    error: Option<String>,
+}
+
+impl Settings {
+    pub(super) fn new(round: u8, settings: SeasonSettings) -> Settings {
+        Settings {
+            round,
+            name: settings.name,
+            team_names: settings.team_names,
+            lineup_size: settings.lineup_size.to_string(),
+            grid_size: settings.grid_size.to_string(),
+            draft_choice: settings.draft_choice,
+            roll_on_choice: settings.roll_on_choice,
+            schedule_choice: settings.draft_schedule.choice(),
+            schedule_input: settings.draft_schedule.input(),
+            enforce_uniqueness: settings.enforce_uniqueness,
+            error: None,
+        }
+    }
+
+    pub(super) fn set_error(&mut self, error: String) {
+        self.error = Some(error);
+    }
+
+    pub(super) fn view(&self) -> Element<'_, SetMessage> {
+        let name = widget::text_input("fantasy season name", &self.name)
+            .on_input(SetMessage::ChangeName)
+            .size(CONTENT)
+            .style(style::text_input::default);
+
+        let teams =
+            widget::Column::from_iter(self.team_names.iter().enumerate().map(|(idx, team)| {
+                widget::text_input("team name", team)
+                    .on_input(move |name| SetMessage::ChangeTeamName(idx, name))
+                    .size(CONTENT)
+                    .style(style::text_input::default)
+                    .into()
+            }))
+            .spacing(PADDING);
+
+        let grid_size = widget::row![
+            widget::text_input("grid size", &self.grid_size)
+                .on_input(SetMessage::ChangeGridSize)
+                .align_x(Alignment::End)
+                .style(style::text_input::default)
+                .size(CONTENT)
+                .width(35),
+            widget::text!(" Drivers, changing this rescores the season")
+                .size(CONTENT)
+                .style(secondary),
+        ]
+        .align_y(Alignment::Center);
+
+        let lineup_size = widget::row![
+            widget::text_input("size", &self.lineup_size)
+                .on_input(SetMessage::ChangeLineupSize)
+                .align_x(Alignment::End)
+                .style(style::text_input::default)
+                .size(CONTENT)
+                .width(35),
+            widget::text!(
+                " Drivers per team from round {}, if it has not been drafted",
+                self.round
+            )
+            .size(CONTENT)
+            .style(secondary),
+        ]
+        .align_y(Alignment::Center);
+
+        let draft_mode = widget::pick_list(
+            vec![
+                DraftChoice::Skip,
+                DraftChoice::RollOn,
+                DraftChoice::ReplaceAll,
+            ],
+            Some(self.draft_choice),
+            SetMessage::DraftChoiceSelected,
+        )
+        .style(style::pick_list::default)
+        .menu_style(style::pick_list::default_menu)
+        .text_size(CONTENT);
+
+        let roll_on_mode = (self.draft_choice == DraftChoice::RollOn).then(|| {
+            widget::pick_list(
+                vec![
+                    RollOnChoice::DropLast,
+                    RollOnChoice::TeamChooses,
+                    RollOnChoice::DropLowestScoring,
+                    RollOnChoice::DropLongestHeld,
+                ],
+                Some(self.roll_on_choice),
+                SetMessage::RollOnChoiceSelected,
+            )
+            .style(style::pick_list::default)
+            .menu_style(style::pick_list::default_menu)
+            .text_size(CONTENT)
+        });
+
+        let schedule = (self.draft_choice != DraftChoice::Skip).then(|| {
+            let input = match self.schedule_choice {
+                ScheduleChoice::EveryRound => None,
+                ScheduleChoice::EveryNRounds => Some("rounds between drafts"),
+                ScheduleChoice::AtRounds => Some("rounds to draft, such as 5, 12"),
+            }
+            .map(|placeholder| {
+                widget::text_input(placeholder, &self.schedule_input)
+                    .on_input(SetMessage::ChangeScheduleInput)
+                    .style(style::text_input::default)
+                    .size(CONTENT)
+                    .width(220)
+            });
+
+            widget::row![
+                widget::text!("Draft").size(CONTENT),
+                widget::pick_list(
+                    vec![
+                        ScheduleChoice::EveryRound,
+                        ScheduleChoice::EveryNRounds,
+                        ScheduleChoice::AtRounds,
+                    ],
+                    Some(self.schedule_choice),
+                    SetMessage::ScheduleChoiceSelected,
+                )
+                .style(style::pick_list::default)
+                .menu_style(style::pick_list::default_menu)
+                .text_size(CONTENT),
+            ]
+            .push_maybe(input)
+            .spacing(PADDING)
+            .align_y(Alignment::Center)
+        });
+
+        widget::column![
+            name,
+            widget::text!("teams").size(CONTENT).style(secondary),
+            widget::container(widget::scrollable(teams)).max_height(250),
+            lineup_size,
+            grid_size,
+            widget::row![draft_mode]
+                .push_maybe(roll_on_mode)
+                .push(
+                    widget::text!(" for rounds that have not been drafted yet")
+                        .size(CONTENT)
+                        .style(secondary),
+                )
+                .spacing(PADDING)
+                .align_y(Alignment::Center),
+        ]
+        .push_maybe(schedule)
+        .extend([
+            widget::toggler(self.enforce_uniqueness)
+                .label("Enforce Uniqueness")
+                .on_toggle(SetMessage::ToggleEnforceUniqueness)
+                .text_size(CONTENT)
+                .size(CONTENT)
+                .into(),
+            widget::vertical_space().into(),
+            widget::text!("{}", self.error.as_deref().unwrap_or_default())
+                .size(CONTENT)
+                .style(danger)
+                .into(),
+            widget::button("save")
+                .on_press_maybe(self.get_settings().map(|_| SetMessage::Save))
+                .style(style::button::primary)
+                .into(),
+        ])
+        .width(Length::Fill)
+        .align_x(Alignment::Center)
+        .spacing(PADDING)
+        .into()
+    }
+
+    pub(super) fn update(&mut self, message: SetMessage) -> PopupAction {
+        match message {
+            SetMessage::ChangeName(name) => self.name = name,
+            SetMessage::ChangeTeamName(idx, name) => self.team_names[idx] = name,
+            SetMessage::ChangeLineupSize(size) => {
+                if size.is_empty() || size.parse::<u8>().is_ok() {
+                    self.lineup_size = size;
+                }
+            }
+            SetMessage::ChangeGridSize(size) => {
+                if size.is_empty() || size.parse::<u8>().is_ok() {
+                    self.grid_size = size;
+                }
+            }
+            SetMessage::DraftChoiceSelected(choice) => self.draft_choice = choice,
+            SetMessage::RollOnChoiceSelected(choice) => self.roll_on_choice = choice,
+            SetMessage::ScheduleChoiceSelected(choice) => self.schedule_choice = choice,
+            SetMessage::ChangeScheduleInput(input) => self.schedule_input = input,
+            SetMessage::ToggleEnforceUniqueness(enforce) => self.enforce_uniqueness = enforce,
+            SetMessage::Save => {
+                if let Some(settings) = self.get_settings() {
+                    return PopupAction::ChangeSettings(settings);
+                }
+            }
+        }
+
+        PopupAction::None
+    }
+
+    fn get_settings(&self) -> Option<SeasonSettings> {
+        Some(SeasonSettings {
+            name: self.name.clone(),
+            team_names: self.team_names.clone(),
+            lineup_size: self.lineup_size.parse().ok()?,
+            grid_size: self.grid_size.parse().ok()?,
+            draft_choice: self.draft_choice,
+            roll_on_choice: self.roll_on_choice,
+            draft_schedule: match self.draft_choice {
+                DraftChoice::Skip => DraftSchedule::EveryRound,
+                _ => DraftSchedule::parse(self.schedule_choice, &self.schedule_input)?,
+            },
+            enforce_uniqueness: self.enforce_uniqueness,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum SetMessage {
+    ChangeName(String),
+    ChangeTeamName(usize, String),
+    ChangeLineupSize(String),
+    ChangeGridSize(String),
+    DraftChoiceSelected(DraftChoice),
+    RollOnChoiceSelected(RollOnChoice),
+    ScheduleChoiceSelected(ScheduleChoice),
+    ChangeScheduleInput(String),
+    ToggleEnforceUniqueness(bool),
+    Save,
+}
@@ -0,0 +1,117 @@
+use super::table_column;
+use crate::fantasy_season::report::TeamReport;
+use crate::vc::{CONTENT, PADDING, style};
+use iced::widget::text::secondary;
+use iced::{Alignment, Element, Length, widget};
+
+pub struct TeamDetail {
+    report: TeamReport,
+}
+
+impl TeamDetail {
+    pub(super) fn new(report: TeamReport) -> TeamDetail {
+        TeamDetail { report }
+    }
+
+    pub(super) fn view<T: 'static>(&self) -> Element<'_, T> {
+        let fmt_round = |round: Option<(u8, i16)>| match round {
+            Some((round, points)) => format!("round {round} ({points} points)"),
+            None => String::from("none yet"),
+        };
+
+        let summary = widget::column![
+            widget::text!("best round: {}", fmt_round(self.report.best_round)).size(CONTENT),
+            widget::text!("worst round: {}", fmt_round(self.report.worst_round)).size(CONTENT),
+        ];
+
+        let rounds = &self.report.rounds;
+        let table = widget::row![
+            table_column(
+                "round",
+                rounds.iter().map(|r| r.round.to_string()).collect(),
+                Alignment::End
+            ),
+            table_column(
+                "lineup (points)",
+                rounds
+                    .iter()
+                    .map(|r| {
+                        r.driver_points
+                            .iter()
+                            .map(|(driver, points)| match points {
+                                Some(points) => format!("{driver:0>2} ({points})"),
+                                None => format!("{driver:0>2} (-)"),
+                            })
+                            .collect::<Vec<_>>()
+                            .join("  ")
+                    })
+                    .collect(),
+                Alignment::Start
+            ),
+            table_column(
+                "points",
+                rounds
+                    .iter()
+                    .map(|r| r.points.map(|p| p.to_string()).unwrap_or(String::from("-")))
+                    .collect(),
+                Alignment::End
+            ),
+            table_column(
+                "total",
+                rounds.iter().map(|r| r.total.to_string()).collect(),
+                Alignment::End
+            ),
+            table_column(
+                "rank",
+                rounds
+                    .iter()
+                    .map(|r| r.rank.map(|p| format!("P{p}")).unwrap_or(String::from("-")))
+                    .collect(),
+                Alignment::End
+            ),
+        ]
+        .spacing(PADDING * 3);
+
+        let changes = widget::Column::from_iter(self.report.lineup_changes.iter().map(|c| {
+            let drivers = |drivers: &[u8]| {
+                drivers
+                    .iter()
+                    .map(|d| format!("{d:0>2}"))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            let text = match (c.added.is_empty(), c.removed.is_empty()) {
+                (false, true) => format!("round {}: drafted {}", c.round, drivers(&c.added)),
+                (true, false) => format!("round {}: released {}", c.round, drivers(&c.removed)),
+                _ => format!(
+                    "round {}: drafted {}, released {}",
+                    c.round,
+                    drivers(&c.added),
+                    drivers(&c.removed)
+                ),
+            };
+            widget::text!("{text}").size(CONTENT).into()
+        }));
+
+        widget::column![
+            summary,
+            widget::container(widget::scrollable(
+                widget::column![
+                    table,
+                    widget::text!("drafting history")
+                        .size(CONTENT)
+                        .style(secondary),
+                    changes
+                ]
+                .spacing(PADDING)
+            ))
+            .padding(PADDING)
+            .height(Length::Fill)
+            .style(style::container::content),
+        ]
+        .width(Length::Fill)
+        .align_x(Alignment::Center)
+        .spacing(PADDING)
+        .into()
+    }
+}
@@ -0,0 +1,161 @@
+use super::PopupAction;
+use crate::vc::utils::*;
+use crate::vc::{CONTENT, PADDING, style};
+use iced::widget::text::{danger, secondary};
+use iced::{Alignment, Element, Length, widget};
+
+pub struct Teams {
+    round: u8,
+    memberships: Vec<(String, u8, Option<u8>)>,
+    name: String,
+    drivers: Vec<String>,
+    handicap: String,
+    error: Option<String>,
+}
+
+impl Teams {
+    pub(super) fn new(
+        round: u8,
+        memberships: Vec<(String, u8, Option<u8>)>,
+        lineup_size: usize,
+    ) -> Teams {
+        Teams {
+            round,
+            memberships,
+            name: String::new(),
+            drivers: vec![String::new(); lineup_size],
+            handicap: String::from("0"),
+            error: None,
+        }
+    }
+
+    pub(super) fn set_error(&mut self, error: String) {
+        self.error = Some(error);
+    }
+
+    pub(super) fn view(&self) -> Element<'_, TMessage> {
+        let teams =
+            widget::Column::from_iter(self.memberships.iter().map(|(team, joined, retired)| {
+                let status = match retired {
+                    Some(retired) => format!("joined round {joined}, retired from round {retired}"),
+                    None => format!("joined round {joined}"),
+                };
+                widget::row![
+                    widget::text!("{team}").size(CONTENT).width(Length::Fill),
+                    widget::text!("{status}").size(CONTENT).style(secondary),
+                    widget::button(widget::text!("retire").size(CONTENT))
+                        .on_press_maybe(
+                            (retired.is_none() && *joined < self.round)
+                                .then(|| TMessage::Retire(team.clone()))
+                        )
+                        .style(style::button::danger),
+                ]
+                .spacing(PADDING)
+                .align_y(Alignment::Center)
+                .into()
+            }))
+            .spacing(PADDING);
+
+        let mut drivers = widget::Row::with_capacity(self.drivers.len()).spacing(5);
+        for (idx, driver) in self.drivers.iter().enumerate() {
+            drivers = drivers.push(
+                widget::text_input(&format!("#{}", idx + 1), driver)
+                    .on_input(move |num| TMessage::ChangeDriverNum(idx, num))
+                    .style(style::text_input::default)
+                    .size(CONTENT)
+                    .width(50),
+            );
+        }
+
+        let new_team = widget::row![
+            widget::text_input("name of team", &self.name)
+                .on_input(TMessage::ChangeName)
+                .width(200)
+                .size(CONTENT)
+                .style(style::text_input::default),
+            drivers,
+            widget::text_input("points", &self.handicap)
+                .on_input(TMessage::ChangeHandicap)
+                .align_x(Alignment::End)
+                .width(60)
+                .size(CONTENT)
+                .style(style::text_input::default),
+            widget::text!("starting points")
+                .size(CONTENT)
+                .style(secondary),
+        ]
+        .spacing(PADDING)
+        .align_y(Alignment::Center);
+
+        widget::column![
+            widget::container(widget::scrollable(teams))
+                .padding(PADDING)
+                .max_height(300)
+                .style(style::container::content),
+            widget::text!(
+                "new teams join from round {}, and teams retire from round {}",
+                self.round,
+                self.round
+            )
+            .size(CONTENT)
+            .style(secondary),
+            new_team,
+            widget::vertical_space(),
+            widget::text!("{}", self.error.as_deref().unwrap_or_default())
+                .size(CONTENT)
+                .style(danger),
+            widget::button("add team")
+                .on_press_maybe(self.get_new_team().map(|_| TMessage::Add))
+                .style(style::button::primary),
+        ]
+        .width(Length::Fill)
+        .align_x(Alignment::Center)
+        .spacing(PADDING)
+        .into()
+    }
+
+    pub(super) fn update(&mut self, message: TMessage) -> PopupAction {
+        match message {
+            TMessage::ChangeName(name) => self.name = name,
+            TMessage::ChangeDriverNum(idx, num) => {
+                if is_valid_driver_input(&num) {
+                    self.drivers[idx] = num;
+                }
+            }
+            TMessage::ChangeHandicap(handicap) => {
+                if handicap.is_empty() || handicap == "-" || handicap.parse::<i16>().is_ok() {
+                    self.handicap = handicap;
+                }
+            }
+            TMessage::Add => {
+                if let Some((name, lineup, handicap)) = self.get_new_team() {
+                    return PopupAction::AddTeam(name, lineup, handicap);
+                }
+            }
+            TMessage::Retire(team) => return PopupAction::RetireTeam(team),
+        }
+
+        PopupAction::None
+    }
+
+    fn get_new_team(&self) -> Option<(String, Vec<u8>, i16)> {
+        if self.name.is_empty() || !is_unique_lineups(self.drivers.iter()) {
+            return None;
+        }
+        let lineup = self
+            .drivers
+            .iter()
+            .map(|d| is_parsable_driver(d).then(|| d.parse::<u8>().ok())?)
+            .collect::<Option<Vec<_>>>()?;
+        Some((self.name.clone(), lineup, self.handicap.parse().ok()?))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum TMessage {
+    ChangeName(String),
+    ChangeDriverNum(usize, String),
+    ChangeHandicap(String),
+    Add,
+    Retire(String),
+}
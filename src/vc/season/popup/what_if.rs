@@ -0,0 +1,168 @@
+use super::{PopupAction, table_column};
+use crate::fantasy_season::score::{AlternateScore, ScoreChoice, ScoreTable};
+use crate::vc::{CONTENT, PADDING, style};
+use iced::widget::text::secondary;
+use iced::{Alignment, Element, Length, widget};
+
+pub struct WhatIf {
+    round: u8,
+    actual_choice: ScoreChoice,
+    actual: Vec<(String, i16)>,
+    choice: Option<ScoreChoice>,
+    table: String,
+    what_if: Option<(AlternateScore, Vec<(String, i16)>)>,
+}
+
+impl WhatIf {
+    pub(super) fn new(round: u8, actual_choice: ScoreChoice, actual: Vec<(String, i16)>) -> WhatIf {
+        WhatIf {
+            round,
+            actual_choice,
+            actual,
+            choice: None,
+            table: String::new(),
+            what_if: None,
+        }
+    }
+
+    pub(super) fn set_results(&mut self, alternate: AlternateScore, points: Vec<(String, i16)>) {
+        self.what_if = Some((alternate, points));
+    }
+
+    pub(super) fn view(&self) -> Element<'_, WIMessage> {
+        let score_mode = widget::pick_list(
+            vec![
+                ScoreChoice::FormulaOne,
+                ScoreChoice::RacePosition,
+                ScoreChoice::Improvement,
+                ScoreChoice::Domination,
+                ScoreChoice::DoubleImprovement,
+            ],
+            self.choice,
+            WIMessage::ChoiceSelected,
+        )
+        .placeholder("Score Mode")
+        .style(style::pick_list::default)
+        .menu_style(style::pick_list::default_menu)
+        .text_size(CONTENT);
+
+        let table = ScoreTable::parse(&self.table);
+        let custom_table = widget::text_input("custom points, eg: 10, 6, 4, 3, 2, 1", &self.table)
+            .on_input(WIMessage::TableChanged)
+            .on_submit_maybe(table.is_some().then_some(WIMessage::ApplyTable))
+            .style(style::text_input::default)
+            .size(CONTENT);
+
+        let apply = widget::button(widget::text!("use table").size(CONTENT))
+            .on_press_maybe(table.is_some().then_some(WIMessage::ApplyTable))
+            .style(style::button::secondary);
+
+        let controls = widget::row![score_mode, custom_table, apply].spacing(PADDING);
+
+        let actual = standings_table(
+            format!("actual ({})", self.actual_choice),
+            &self.actual,
+            None,
+        );
+
+        let what_if = match &self.what_if {
+            Some((alternate, points)) => {
+                standings_table(format!("what if ({alternate})"), points, Some(&self.actual))
+            }
+            None => widget::text!("pick a score mode or enter a custom table")
+                .size(CONTENT)
+                .style(secondary)
+                .into(),
+        };
+
+        widget::column![
+            controls,
+            widget::text!("standings after round {}", self.round)
+                .size(CONTENT)
+                .style(secondary),
+            widget::container(widget::scrollable(
+                widget::row![actual, what_if].spacing(PADDING * 6)
+            ))
+            .padding(PADDING)
+            .height(Length::Fill)
+            .style(style::container::content),
+        ]
+        .width(Length::Fill)
+        .align_x(Alignment::Center)
+        .spacing(PADDING)
+        .into()
+    }
+
+    pub(super) fn update(&mut self, message: WIMessage) -> PopupAction {
+        match message {
+            WIMessage::ChoiceSelected(choice) => {
+                self.choice = Some(choice);
+                PopupAction::WhatIf(AlternateScore::Choice(choice))
+            }
+            WIMessage::TableChanged(table) => {
+                self.table = table;
+                PopupAction::None
+            }
+            WIMessage::ApplyTable => match ScoreTable::parse(&self.table) {
+                Some(table) => {
+                    self.choice = None;
+                    PopupAction::WhatIf(AlternateScore::Table(table))
+                }
+                None => PopupAction::None,
+            },
+        }
+    }
+}
+
+// a standings table, with how far each team moved compared to the actual standings if given
+fn standings_table<'a>(
+    title: String,
+    points: &[(String, i16)],
+    actual: Option<&[(String, i16)]>,
+) -> Element<'a, WIMessage> {
+    let mut columns = widget::row![
+        table_column(
+            "pos",
+            (1..=points.len()).map(|p| format!("P{p}")).collect(),
+            Alignment::End
+        ),
+        table_column(
+            "team",
+            points.iter().map(|(team, _)| team.clone()).collect(),
+            Alignment::Start
+        ),
+        table_column(
+            "points",
+            points.iter().map(|(_, p)| p.to_string()).collect(),
+            Alignment::End
+        ),
+    ]
+    .spacing(PADDING * 3);
+
+    if let Some(actual) = actual {
+        let changes = points
+            .iter()
+            .enumerate()
+            .map(|(pos, (team, _))| {
+                let before = actual.iter().position(|(t, _)| t == team).unwrap_or(pos);
+                match before as isize - pos as isize {
+                    0 => String::from("="),
+                    moved if moved > 0 => format!("+{moved}"),
+                    moved => moved.to_string(),
+                }
+            })
+            .collect();
+        columns = columns.push(table_column("change", changes, Alignment::End));
+    }
+
+    widget::column![widget::text!("{title}").size(CONTENT), columns]
+        .spacing(PADDING)
+        .into()
+}
+
+#[derive(Clone, Debug)]
+pub enum WIMessage {
+    ChoiceSelected(ScoreChoice),
+    TableChanged(String),
+    ApplyTable,
+}
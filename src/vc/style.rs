@@ -54,6 +54,17 @@ pub mod button {
         }
     }
 
+    pub fn neutral(theme: &Theme, status: Status) -> Style {
+        let palette = theme.extended_palette();
+
+        match status {
+            Status::Active => style(palette.background.strong, true),
+            Status::Hovered => style(palette.background.weak, true),
+            Status::Pressed => style(palette.background.weak, false),
+            Status::Disabled => style(palette.background.base, false),
+        }
+    }
+
     pub fn danger(theme: &Theme, status: Status) -> Style {
         let palette = theme.extended_palette();
 
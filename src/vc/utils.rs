@@ -1,12 +1,20 @@
 use std::collections::HashSet;
 use std::hash::Hash;
 
-pub fn is_unique_lineups<T: Iterator>(mut lineups: T) -> bool
+pub fn is_unique_lineups<T: Iterator>(lineups: T) -> bool
 where
-    <T as Iterator>::Item: Eq + Hash,
+    <T as Iterator>::Item: Eq + Hash + Clone,
+{
+    find_duplicate(lineups).is_none()
+}
+
+// the first item that has already been seen
+pub fn find_duplicate<T: Iterator>(mut lineups: T) -> Option<T::Item>
+where
+    <T as Iterator>::Item: Eq + Hash + Clone,
 {
     let mut seen = HashSet::new();
-    lineups.all(|y| seen.insert(y))
+    lineups.find(|y| !seen.insert(y.clone()))
 }
 
 pub fn is_valid_driver_input(new: &str) -> bool {